@@ -0,0 +1,28 @@
+//! Uses rust_utils from a `#![no_std]` crate, so building this fails if the allocation-free
+//! adaptors, or the `alloc` ones with that feature, come to depend on std.
+#![no_std]
+#![cfg_attr(feature = "alloc", feature(alloc))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+extern crate rust_utils;
+
+use rust_utils::iter::*;
+
+pub fn adaptors(out: &mut [i32; 6]) -> Option<i32> {
+    let vals = [1, 3, 5].iter().cloned()
+        .interleave([2, 4, 6].iter().cloned())
+        .take_until(|&x| x == 6);
+
+    for (o, v) in out.iter_mut().zip(vals) {
+        *o = v;
+    }
+
+    let pairs = out.iter().cloned().pairwise().count();
+    out.iter().cloned().running_sum().last().map(|x| x + pairs as i32)
+}
+
+#[cfg(feature = "alloc")]
+pub fn alloc_adaptors(vals: &[i32]) -> alloc::vec::Vec<alloc::vec::Vec<i32>> {
+    vals.iter().cloned().chunked(2).collect()
+}
\ No newline at end of file
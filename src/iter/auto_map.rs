@@ -1,6 +1,10 @@
-use std::fmt;
-use std::iter::FromIterator;
-use std::marker::PhantomData;
+use core::fmt;
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 #[derive(Clone)]
@@ -54,6 +58,13 @@ pub trait AutoMap : Iterator
         AutoMapInto{ iter: self, _b_marker: PhantomData }
     }
 
+    fn auto_map_ref<'a, A: 'a, B>(self) -> AutoMapInto<B, Self>
+        where Self: Sized + Iterator<Item=&'a A>,
+            B: From<&'a A>
+    {
+        AutoMapInto{ iter: self, _b_marker: PhantomData }
+    }
+
     fn auto_map_collect<A, B, V>(self) -> V
         where Self: Sized + Iterator<Item=A>,
             B: From<A>,
@@ -61,6 +72,17 @@ pub trait AutoMap : Iterator
     {
         self.auto_map().collect()
     }
+
+    /// Converts each element into a key/value pair and collects them into a map. Later pairs
+    /// replace earlier ones with the same key.
+    #[cfg(feature = "std")]
+    fn auto_map_collect_map<A, K, V>(self) -> HashMap<K, V>
+        where Self: Sized + Iterator<Item=A>,
+            (K, V): From<A>,
+            K: Hash + Eq
+    {
+        self.auto_map().collect()
+    }
 }
 
 impl<T: ?Sized> AutoMap for T
@@ -87,6 +109,27 @@ mod tests {
         assert_eq!(foos, vec![Foo(1), Foo(2), Foo(3)]);
     }
 
+    #[derive(Debug, Eq, PartialEq)]
+    struct Name(String);
+
+    struct Person {
+        name: String,
+    }
+
+    impl<'a> From<&'a Person> for Name {
+        fn from(other: &'a Person) -> Name {
+            Name(other.name.clone())
+        }
+    }
+
+    #[test]
+    fn map_ref() {
+        let people = [Person{ name: "Alice".to_owned() }, Person{ name: "Bob".to_owned() }];
+        let names: Vec<Name> = people.iter().auto_map_ref().collect();
+
+        assert_eq!(names, vec![Name("Alice".to_owned()), Name("Bob".to_owned())]);
+    }
+
     #[test]
     fn collect() {
         let ints = 1_u32..4;
@@ -95,4 +138,32 @@ mod tests {
 
         assert_eq!(foos, collected);
     }
+
+    struct Setting {
+        name: &'static str,
+        value: u32,
+    }
+
+    impl From<Setting> for (&'static str, u32) {
+        fn from(other: Setting) -> (&'static str, u32) {
+            (other.name, other.value)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collect_map() {
+        let settings = vec![
+            Setting{ name: "width", value: 80 },
+            Setting{ name: "height", value: 24 },
+            Setting{ name: "width", value: 100 },
+        ];
+        let map = settings.into_iter().auto_map_collect_map();
+
+        let mut expected = HashMap::new();
+        expected.insert("width", 100);
+        expected.insert("height", 24);
+
+        assert_eq!(map, expected);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,78 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Yields the elements of the underlying iterator in `Vec`s of a fixed size. The final chunk may
+/// be shorter if the elements don't divide evenly.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Chunked<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for Chunked<I>
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<_> = self.iter.by_ref().take(self.size).collect();
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let chunks = |n: usize| n / self.size + (n % self.size != 0) as usize;
+
+        (chunks(low), high.map(chunks))
+    }
+}
+
+pub trait Chunks : Iterator
+{
+    fn chunked(self, size: usize) -> Chunked<Self>
+        where Self: Sized
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunked{ iter: self, size: size }
+    }
+}
+
+impl<T: ?Sized> Chunks for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact() {
+        let chunks: Vec<_> = (1..7).chunked(3).collect();
+
+        assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn trailing_partial() {
+        let chunks: Vec<_> = (1..6).chunked(2).collect();
+
+        assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn size_hint() {
+        assert_eq!((1..6).chunked(2).size_hint(), (3, Some(3)));
+        assert_eq!((1..7).chunked(3).size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero_size() {
+        let _ = (1..6).chunked(0);
+    }
+}
\ No newline at end of file
@@ -1,9 +1,19 @@
-use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, HashMap, HashSet};
+#[cfg(feature = "std")]
 use std::hash::Hash;
+#[cfg(feature = "std")]
 use std::cmp::Eq;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+#[cfg(all(feature = "indexmap", feature = "std"))]
+use indexmap::IndexMap;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 pub trait CollectMapVec : Iterator
 {
+    #[cfg(feature = "std")]
     fn collect_map_vec_by<K, V, FA>(self, f: FA) -> HashMap<K, Vec<V>>
         where Self: Sized + Iterator<Item=V>,
             K: Hash + Eq,
@@ -12,6 +22,35 @@ pub trait CollectMapVec : Iterator
         self.map(|v| (f(&v), v)).collect_map_vec()
     }
 
+    #[cfg(feature = "std")]
+    fn collect_map_vec_try_by<K, V, E, FA>(self, f: FA) -> Result<HashMap<K, Vec<V>>, E>
+        where Self: Sized + Iterator<Item=V>,
+            K: Hash + Eq,
+            FA: Fn(&V) -> Result<K, E>
+    {
+        let mut map = HashMap::<K, Vec<V>>::new();
+
+        for val in self {
+            let vec = map.entry(f(&val)?).or_insert(vec![]);
+            vec.push(val);
+        }
+
+        Ok(map)
+    }
+
+    #[cfg(feature = "std")]
+    fn collect_map_vec_keyed<K, V, FA>(self, f: FA) -> HashMap<K, Vec<(K, V)>>
+        where Self: Sized + Iterator<Item=V>,
+            K: Hash + Eq + Clone,
+            FA: Fn(&V) -> K
+    {
+        self.map(|v| {
+            let key = f(&v);
+            (key.clone(), (key, v))
+        }).collect_map_vec()
+    }
+
+    #[cfg(feature = "std")]
     fn collect_map_vec<K, V>(self) -> HashMap<K, Vec<V>>
         where Self: Sized + Iterator<Item=(K, V)>,
               K: Hash + Eq
@@ -25,6 +64,107 @@ pub trait CollectMapVec : Iterator
 
         map
     }
+
+    /// Collects key/value pairs into a map, keeping the last value seen for each key.
+    #[cfg(feature = "std")]
+    fn collect_map_last<K, V>(self) -> HashMap<K, V>
+        where Self: Sized + Iterator<Item=(K, V)>,
+              K: Hash + Eq
+    {
+        self.collect()
+    }
+
+    /// Collects key/value pairs into a map, keeping the first value seen for each key.
+    #[cfg(feature = "std")]
+    fn collect_map_first<K, V>(self) -> HashMap<K, V>
+        where Self: Sized + Iterator<Item=(K, V)>,
+              K: Hash + Eq
+    {
+        let mut map = HashMap::<K, V>::new();
+
+        for (key, val) in self {
+            map.entry(key).or_insert(val);
+        }
+
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn collect_map_set_by<K, V, FA>(self, f: FA) -> HashMap<K, HashSet<V>>
+        where Self: Sized + Iterator<Item=V>,
+            K: Hash + Eq,
+            V: Hash + Eq,
+            FA: Fn(&V) -> K
+    {
+        self.map(|v| (f(&v), v)).collect_map_set()
+    }
+
+    #[cfg(feature = "std")]
+    fn collect_map_set<K, V>(self) -> HashMap<K, HashSet<V>>
+        where Self: Sized + Iterator<Item=(K, V)>,
+              K: Hash + Eq,
+              V: Hash + Eq
+    {
+        let mut map = HashMap::<K, HashSet<V>>::new();
+
+        for (key, val) in self {
+            let set = map.entry(key).or_insert_with(HashSet::new);
+            set.insert(val);
+        }
+
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn fold_by_key<K, V, Acc, FK, FF>(self, key_fn: FK, init: Acc, fold_fn: FF) -> HashMap<K, Acc>
+        where Self: Sized + Iterator<Item=V>,
+            K: Hash + Eq,
+            Acc: Clone,
+            FK: Fn(&V) -> K,
+            FF: Fn(Acc, V) -> Acc
+    {
+        let mut map = HashMap::<K, Acc>::new();
+
+        for val in self {
+            let key = key_fn(&val);
+            let acc = map.remove(&key).unwrap_or_else(|| init.clone());
+            map.insert(key, fold_fn(acc, val));
+        }
+
+        map
+    }
+
+    fn group_by_key_sorted<K, V, FA>(self, f: FA) -> BTreeMap<K, Vec<V>>
+        where Self: Sized + Iterator<Item=V>,
+            K: Ord,
+            FA: Fn(&V) -> K
+    {
+        let mut map = BTreeMap::<K, Vec<V>>::new();
+
+        for val in self {
+            let vec = map.entry(f(&val)).or_insert(vec![]);
+            vec.push(val);
+        }
+
+        map
+    }
+
+    /// Groups the values by key, with the groups in the order their keys were first seen.
+    #[cfg(all(feature = "indexmap", feature = "std"))]
+    fn group_by_key_ordered<K, V, FA>(self, f: FA) -> IndexMap<K, Vec<V>>
+        where Self: Sized + Iterator<Item=V>,
+            K: Hash + Eq,
+            FA: Fn(&V) -> K
+    {
+        let mut map = IndexMap::<K, Vec<V>>::new();
+
+        for val in self {
+            let vec = map.entry(f(&val)).or_insert(vec![]);
+            vec.push(val);
+        }
+
+        map
+    }
 }
 
 impl<T: ?Sized> CollectMapVec for T
@@ -34,6 +174,7 @@ impl<T: ?Sized> CollectMapVec for T
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn group_by() {
         let ints = 1_u32..10;
@@ -46,6 +187,42 @@ mod tests {
         assert_eq!(odd_even, expected);
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_try_by() {
+        let strings = vec!["1a", "2b", "1c"];
+        let grouped = strings.into_iter().collect_map_vec_try_by(|s| s[..1].parse::<u32>());
+
+        let mut expected = HashMap::new();
+        expected.insert(1, vec!["1a", "1c"]);
+        expected.insert(2, vec!["2b"]);
+
+        assert_eq!(grouped, Ok(expected));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_try_by_error() {
+        let strings = vec!["1a", "xb", "1c"];
+        let grouped = strings.into_iter().collect_map_vec_try_by(|s| s[..1].parse::<u32>());
+
+        assert_eq!(grouped, Err("x".parse::<u32>().unwrap_err()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_keyed() {
+        let words = vec!["apple", "avocado", "banana"];
+        let grouped = words.into_iter().collect_map_vec_keyed(|w| w.chars().next().unwrap());
+
+        let mut expected = HashMap::new();
+        expected.insert('a', vec![('a', "apple"), ('a', "avocado")]);
+        expected.insert('b', vec![('b', "banana")]);
+
+        assert_eq!(grouped, expected);
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn group() {
         let ints = 1_u32..10;
@@ -58,4 +235,95 @@ mod tests {
 
         assert_eq!(odd_even, expected);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn map_last() {
+        let pairs = vec![(1, "a"), (2, "b"), (1, "c")];
+        let map = pairs.into_iter().collect_map_last();
+
+        let mut expected = HashMap::new();
+        expected.insert(1, "c");
+        expected.insert(2, "b");
+
+        assert_eq!(map, expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn map_first() {
+        let pairs = vec![(1, "a"), (2, "b"), (1, "c")];
+        let map = pairs.into_iter().collect_map_first();
+
+        let mut expected = HashMap::new();
+        expected.insert(1, "a");
+        expected.insert(2, "b");
+
+        assert_eq!(map, expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_by_set() {
+        let ints = (1_u32..10).chain(1..10);
+        let by_mod = ints.collect_map_set_by(|i| i%3);
+
+        let mut expected = HashMap::new();
+        expected.insert(0, [3,6,9].iter().cloned().collect::<HashSet<_>>());
+        expected.insert(1, [1,4,7].iter().cloned().collect::<HashSet<_>>());
+        expected.insert(2, [2,5,8].iter().cloned().collect::<HashSet<_>>());
+
+        assert_eq!(by_mod, expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_set() {
+        let ints = 1_u32..10;
+        let odd_even = ints.map(|i| (i%2 == 0, i%3))
+            .collect_map_set();
+
+        let mut expected = HashMap::new();
+        expected.insert(true, [0,1,2].iter().cloned().collect::<HashSet<_>>());
+        expected.insert(false, [0,1,2].iter().cloned().collect::<HashSet<_>>());
+
+        assert_eq!(odd_even, expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fold_by() {
+        let ints = 1_u32..10;
+        let sums = ints.fold_by_key(|i| i%2 == 0, 0, |acc, i| acc + i);
+
+        let mut expected = HashMap::new();
+        expected.insert(true, 20);
+        expected.insert(false, 25);
+
+        assert_eq!(sums, expected);
+    }
+
+    #[test]
+    fn group_by_sorted() {
+        let ints = 1_u32..10;
+        let by_mod = ints.group_by_key_sorted(|i| 3 - i%3);
+
+        let keys: Vec<_> = by_mod.keys().cloned().collect();
+        assert_eq!(keys, vec![1,2,3]);
+
+        let groups: Vec<_> = by_mod.into_iter().map(|(_, v)| v).collect();
+        assert_eq!(groups, vec![vec![2,5,8], vec![1,4,7], vec![3,6,9]]);
+    }
+
+    #[cfg(all(feature = "indexmap", feature = "std"))]
+    #[test]
+    fn group_by_ordered() {
+        let grouped = vec![3, 1, 3, 2, 1].into_iter().group_by_key_ordered(|&i| i);
+
+        let keys: Vec<_> = grouped.keys().cloned().collect();
+        assert_eq!(keys, vec![3, 1, 2]);
+
+        let groups: Vec<_> = grouped.into_iter().map(|(_, v)| v).collect();
+        assert_eq!(groups, vec![vec![3, 3], vec![1, 1], vec![2]]);
+    }
 }
\ No newline at end of file
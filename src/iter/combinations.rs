@@ -0,0 +1,122 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Combinations<I: Iterator> {
+    iter: I,
+    pool: Vec<I::Item>,
+    indices: Vec<usize>,
+    k: usize,
+    started: bool,
+    done: bool,
+}
+
+impl<I> Combinations<I>
+    where I: Iterator,
+        I::Item: Clone
+{
+    fn current(&self) -> Vec<I::Item> {
+        self.indices.iter().map(|&i| self.pool[i].clone()).collect()
+    }
+
+    fn advance(&mut self) -> bool {
+        let n = self.pool.len();
+        let k = self.k;
+
+        let pos = match (0..k).rev().find(|&i| self.indices[i] != i + n - k) {
+            Some(pos) => pos,
+            None => return false,
+        };
+
+        self.indices[pos] += 1;
+        for j in pos+1..k {
+            self.indices[j] = self.indices[j-1] + 1;
+        }
+
+        true
+    }
+}
+
+impl<I> Iterator for Combinations<I>
+    where I: Iterator,
+        I::Item: Clone
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+            self.pool.extend(self.iter.by_ref());
+
+            if self.k > self.pool.len() {
+                self.done = true;
+                return None;
+            }
+
+            self.indices = (0..self.k).collect();
+        } else if !self.advance() {
+            self.done = true;
+            return None;
+        }
+
+        Some(self.current())
+    }
+}
+
+pub trait CombinationsOf : Iterator
+{
+    fn combinations(self, k: usize) -> Combinations<Self>
+        where Self: Sized,
+            Self::Item: Clone
+    {
+        Combinations {
+            iter: self,
+            pool: vec![],
+            indices: vec![],
+            k: k,
+            started: false,
+            done: false,
+        }
+    }
+}
+
+impl<T: ?Sized> CombinationsOf for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs() {
+        let combs: Vec<_> = vec![1, 2, 3].into_iter().combinations(2).collect();
+
+        assert_eq!(combs, vec![vec![1, 2], vec![1, 3], vec![2, 3]]);
+    }
+
+    #[test]
+    fn triples_of_four() {
+        let combs: Vec<_> = (1..5).combinations(3).collect();
+
+        assert_eq!(combs, vec![vec![1, 2, 3], vec![1, 2, 4], vec![1, 3, 4], vec![2, 3, 4]]);
+    }
+
+    #[test]
+    fn k_too_large() {
+        let combs: Vec<_> = (1..3).combinations(3).collect();
+
+        assert!(combs.is_empty());
+    }
+
+    #[test]
+    fn k_zero() {
+        let combs: Vec<Vec<u32>> = (1..3).combinations(0).collect();
+
+        assert_eq!(combs, vec![Vec::<u32>::new()]);
+    }
+}
\ No newline at end of file
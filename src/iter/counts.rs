@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::cmp::Eq;
+
+use super::Pairs;
+
+pub trait Counts : Iterator
+{
+    fn counts<T>(self) -> HashMap<T, usize>
+        where Self: Sized + Iterator<Item=T>,
+            T: Hash + Eq
+    {
+        let mut map = HashMap::<T, usize>::new();
+
+        for val in self {
+            *map.entry(val).or_insert(0) += 1;
+        }
+
+        map
+    }
+
+    fn count_by_key<K, V, FA>(self, f: FA) -> HashMap<K, usize>
+        where Self: Sized + Iterator<Item=V>,
+            K: Hash + Eq,
+            FA: Fn(&V) -> K
+    {
+        self.map(|v| f(&v)).counts()
+    }
+
+    fn transition_counts<V>(self) -> HashMap<(V, V), usize>
+        where Self: Sized + Iterator<Item=V>,
+            V: Hash + Eq + Clone
+    {
+        self.pairwise().counts()
+    }
+
+    /// Returns the `n` most frequent elements along with their counts, most frequent first.
+    ///
+    /// Elements with equal counts are ordered by where they first appeared.
+    fn most_common<V>(self, n: usize) -> Vec<(V, usize)>
+        where Self: Sized + Iterator<Item=V>,
+            V: Hash + Eq
+    {
+        // Maps each element to its first position and count.
+        let mut map = HashMap::<V, (usize, usize)>::new();
+        for (i, val) in self.enumerate() {
+            map.entry(val).or_insert((i, 0)).1 += 1;
+        }
+
+        let mut counts: Vec<_> = map.into_iter().collect();
+        counts.sort_by(|a, b| (b.1).1.cmp(&(a.1).1).then((a.1).0.cmp(&(b.1).0)));
+        counts.truncate(n);
+
+        counts.into_iter().map(|(val, (_, count))| (val, count)).collect()
+    }
+}
+
+impl<T: ?Sized> Counts for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chars() {
+        let counts = "aabbbc".chars().counts();
+
+        let mut expected = HashMap::new();
+        expected.insert('a', 2);
+        expected.insert('b', 3);
+        expected.insert('c', 1);
+
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn ints() {
+        let counts = vec![1, 2, 1, 3, 1, 2].into_iter().counts();
+
+        let mut expected = HashMap::new();
+        expected.insert(1, 3);
+        expected.insert(2, 2);
+        expected.insert(3, 1);
+
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn empty() {
+        let counts = Vec::<u32>::new().into_iter().counts();
+
+        assert!(counts.is_empty());
+    }
+
+    #[test]
+    fn count_by() {
+        let counts = (1_u32..10).count_by_key(|i| i%2 == 0);
+
+        let mut expected = HashMap::new();
+        expected.insert(true, 4);
+        expected.insert(false, 5);
+
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn transitions() {
+        let counts = "abab".chars().transition_counts();
+
+        let mut expected = HashMap::new();
+        expected.insert(('a', 'b'), 2);
+        expected.insert(('b', 'a'), 1);
+
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn transitions_too_short() {
+        assert!(Some(1).into_iter().transition_counts().is_empty());
+    }
+
+    #[test]
+    fn most_common() {
+        assert_eq!("aaabbc".chars().most_common(2), vec![('a', 3), ('b', 2)]);
+        assert_eq!("aaabbc".chars().most_common(10), vec![('a', 3), ('b', 2), ('c', 1)]);
+        assert!("aaabbc".chars().most_common(0).is_empty());
+    }
+
+    #[test]
+    fn most_common_ties() {
+        assert_eq!("cbacba".chars().most_common(3), vec![('c', 2), ('b', 2), ('a', 2)]);
+        assert_eq!("zyyxxw".chars().most_common(3), vec![('y', 2), ('x', 2), ('z', 1)]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+pub trait Deal : Iterator
+{
+    fn deal<V>(self, n: usize) -> Vec<Vec<V>>
+        where Self: Sized + Iterator<Item=V>
+    {
+        assert!(n != 0, "cannot deal into zero piles");
+
+        let mut piles: Vec<Vec<V>> = (0..n).map(|_| vec![]).collect();
+
+        for (i, val) in self.enumerate() {
+            piles[i % n].push(val);
+        }
+
+        piles
+    }
+}
+
+impl<T: ?Sized> Deal for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn three_piles() {
+        let piles = (1..7).deal(3);
+
+        assert_eq!(piles, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn uneven() {
+        let piles = (1..6).deal(3);
+
+        assert_eq!(piles, vec![vec![1, 4], vec![2, 5], vec![3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot deal into zero piles")]
+    fn zero_piles() {
+        (1..7).deal(0);
+    }
+}
\ No newline at end of file
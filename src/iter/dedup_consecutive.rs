@@ -0,0 +1,144 @@
+/// Yields the first element of each run of consecutive equal elements.
+///
+/// Each element is yielded as soon as it is seen, and a copy is kept to skip the rest of its run.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct DedupConsecutive<I: Iterator> {
+    iter: I,
+    last: Option<I::Item>,
+}
+
+impl<I> Iterator for DedupConsecutive<I>
+    where I: Iterator,
+        I::Item: PartialEq + Clone
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let val = self.iter.next()?;
+            if self.last.as_ref() != Some(&val) {
+                self.last = Some(val.clone());
+                return Some(val);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let low = if low > 0 && self.last.is_none() { 1 } else { 0 };
+
+        (low, high)
+    }
+}
+
+/// Yields the first element of each run of consecutive elements with equal keys.
+///
+/// As with [`DedupConsecutive`](struct.DedupConsecutive.html), each element is yielded as soon as
+/// it is seen. Only its key is kept to skip the rest of its run.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct DedupConsecutiveByKey<I, K, F> {
+    iter: I,
+    last: Option<K>,
+    f: F,
+}
+
+impl<I, K, F> Iterator for DedupConsecutiveByKey<I, K, F>
+    where I: Iterator,
+        K: PartialEq,
+        F: FnMut(&I::Item) -> K
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let val = self.iter.next()?;
+            let key = (self.f)(&val);
+            if self.last.as_ref() != Some(&key) {
+                self.last = Some(key);
+                return Some(val);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let low = if low > 0 && self.last.is_none() { 1 } else { 0 };
+
+        (low, high)
+    }
+}
+
+pub trait Dedup : Iterator
+{
+    fn dedup_consecutive(self) -> DedupConsecutive<Self>
+        where Self: Sized,
+            Self::Item: PartialEq + Clone
+    {
+        DedupConsecutive{ iter: self, last: None }
+    }
+
+    fn dedup_consecutive_by_key<K, F>(self, f: F) -> DedupConsecutiveByKey<Self, K, F>
+        where Self: Sized,
+            K: PartialEq,
+            F: FnMut(&Self::Item) -> K
+    {
+        DedupConsecutiveByKey{ iter: self, last: None, f: f }
+    }
+}
+
+impl<T: ?Sized> Dedup for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedup() {
+        let vals = vec![1, 1, 2, 2, 2, 3, 1];
+        let deduped: Vec<_> = vals.into_iter().dedup_consecutive().collect();
+
+        assert_eq!(deduped, vec![1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn dedup_infinite() {
+        let deduped: Vec<_> = (0..).map(|i| i / 3).dedup_consecutive().take(4).collect();
+
+        assert_eq!(deduped, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn dedup_by_key() {
+        let vals = vec![1, 3, 2, 4, 6, 5, 8];
+        let deduped: Vec<_> = vals.into_iter().dedup_consecutive_by_key(|i| i % 2).collect();
+
+        assert_eq!(deduped, vec![1, 2, 5, 8]);
+    }
+
+    #[test]
+    fn dedup_yields_run_start_immediately() {
+        use std::cell::Cell;
+        use std::iter;
+
+        let pulled = Cell::new(0);
+        let first = iter::repeat(1).inspect(|_| pulled.set(pulled.get() + 1))
+            .dedup_consecutive()
+            .next();
+        assert_eq!(first, Some(1));
+        assert_eq!(pulled.get(), 1);
+
+        let pulled = Cell::new(0);
+        let mut deduped = vec![1, 1, 1, 1, 2].into_iter()
+            .inspect(|_| pulled.set(pulled.get() + 1))
+            .dedup_consecutive_by_key(|&i| i);
+        assert_eq!(deduped.next(), Some(1));
+        assert_eq!(pulled.get(), 1);
+        assert_eq!(deduped.next(), Some(2));
+        assert_eq!(pulled.get(), 5);
+    }
+}
\ No newline at end of file
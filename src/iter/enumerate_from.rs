@@ -0,0 +1,57 @@
+/// Yields the elements of the underlying iterator paired with their index, counting up from a
+/// given start. The index saturates at `usize::MAX` rather than overflowing.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct EnumerateFrom<I> {
+    iter: I,
+    index: usize,
+}
+
+impl<I: Iterator> Iterator for EnumerateFrom<I>
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = self.iter.next()?;
+        let index = self.index;
+        self.index = self.index.saturating_add(1);
+
+        Some((index, val))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait EnumerateStart : Iterator
+{
+    fn enumerate_from(self, start: usize) -> EnumerateFrom<Self>
+        where Self: Sized
+    {
+        EnumerateFrom{ iter: self, index: start }
+    }
+}
+
+impl<T: ?Sized> EnumerateStart for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_ten() {
+        let vals: Vec<_> = vec!['a', 'b'].into_iter().enumerate_from(10).collect();
+
+        assert_eq!(vals, vec![(10, 'a'), (11, 'b')]);
+    }
+
+    #[test]
+    fn saturates() {
+        let vals: Vec<_> = vec!['a', 'b', 'c'].into_iter().enumerate_from(usize::max_value() - 1).collect();
+
+        assert_eq!(vals, vec![(usize::max_value() - 1, 'a'), (usize::max_value(), 'b'), (usize::max_value(), 'c')]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FlatMap;
+use std::vec;
+
+/// Yields the values of a grouped map, ordered by key.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct FlattenSortedByKey<K, V> {
+    iter: FlatMap<vec::IntoIter<(K, Vec<V>)>, Vec<V>, fn((K, Vec<V>)) -> Vec<V>>,
+}
+
+impl<K, V> Iterator for FlattenSortedByKey<K, V> {
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<V> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+fn values<K, V>(entry: (K, Vec<V>)) -> Vec<V> {
+    entry.1
+}
+
+/// Returns an iterator over all the values in a grouped map, such as one made by
+/// `collect_map_vec`, with the groups in key order. Values within a group keep their order.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::iter::{CollectMapVec, flatten_sorted_by_key};
+///
+/// let grouped = vec![3, 1, 2, 1].into_iter().collect_map_vec_by(|&i| i);
+/// let vals: Vec<_> = flatten_sorted_by_key(grouped).collect();
+/// assert_eq!(vals, vec![1, 1, 2, 3]);
+/// ```
+pub fn flatten_sorted_by_key<K: Ord + Hash, V>(map: HashMap<K, Vec<V>>) -> FlattenSortedByKey<K, V> {
+    let mut groups: Vec<_> = map.into_iter().collect();
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+    FlattenSortedByKey {
+        iter: groups.into_iter().flat_map(values as fn((K, Vec<V>)) -> Vec<V>),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iter::CollectMapVec;
+
+    #[test]
+    fn round_trip_parity() {
+        let grouped = (1_u32..10).collect_map_vec_by(|i| i % 2 == 0);
+        let vals: Vec<_> = flatten_sorted_by_key(grouped).collect();
+
+        assert_eq!(vals, vec![1, 3, 5, 7, 9, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn empty() {
+        let vals: Vec<u32> = flatten_sorted_by_key(HashMap::<u32, Vec<u32>>::new()).collect();
+
+        assert!(vals.is_empty());
+    }
+}
\ No newline at end of file
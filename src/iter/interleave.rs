@@ -0,0 +1,342 @@
+use core::iter::Map;
+
+use super::{AutoMap, AutoMapInto};
+
+/// Controls what happens when one of the interleaved iterators runs out.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum InterleaveMode {
+    /// Stop as soon as the iterator whose turn it is runs out, so the output contains either the
+    /// same number of elements from each iterator, or one more from the first.
+    Shortest,
+    /// Once either iterator runs out, yield the rest of the other one.
+    Longest,
+}
+
+/// Tags an element from `interleave_tagged` with the iterator it came from.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Source<A, B> {
+    /// An element from the first iterator.
+    A(A),
+    /// An element from the second iterator.
+    B(B),
+}
+
+/// Alternates between the elements of two iterators, starting with the first. What happens
+/// when one runs out is determined by the `InterleaveMode`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct InterleaveIters<IA, IB> {
+    a: IA,
+    b: IB,
+    a_next: bool,
+    mode: InterleaveMode,
+}
+
+fn interleaved_len(first: usize, second: usize) -> Option<usize> {
+    if first <= second {
+        first.checked_mul(2)
+    } else {
+        second.checked_mul(2).and_then(|l| l.checked_add(1))
+    }
+}
+
+impl<IA, IB> Iterator for InterleaveIters<IA, IB>
+    where IA: Iterator,
+        IB: Iterator<Item=IA::Item>
+{
+    type Item = IA::Item;
+
+    fn next(&mut self) -> Option<IA::Item> {
+        let val = match (self.a_next, self.mode) {
+            (true, InterleaveMode::Shortest) => self.a.next(),
+            (false, InterleaveMode::Shortest) => self.b.next(),
+            (true, InterleaveMode::Longest) => self.a.next().or_else(|| self.b.next()),
+            (false, InterleaveMode::Longest) => self.b.next().or_else(|| self.a.next()),
+        }?;
+
+        self.a_next = !self.a_next;
+
+        Some(val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_low, a_high) = self.a.size_hint();
+        let (b_low, b_high) = self.b.size_hint();
+
+        if self.mode == InterleaveMode::Longest {
+            let high = match (a_high, b_high) {
+                (Some(a), Some(b)) => a.checked_add(b),
+                _ => None,
+            };
+
+            return (a_low.saturating_add(b_low), high);
+        }
+
+        let (first_low, first_high, second_low, second_high) = if self.a_next {
+            (a_low, a_high, b_low, b_high)
+        } else {
+            (b_low, b_high, a_low, a_high)
+        };
+
+        let low = interleaved_len(first_low, second_low).unwrap_or(usize::max_value());
+        let high = match (first_high, second_high) {
+            (Some(first), Some(second)) => interleaved_len(first, second),
+            (Some(first), None) => first.checked_mul(2),
+            (None, Some(second)) => second.checked_mul(2).and_then(|l| l.checked_add(1)),
+            (None, None) => None,
+        };
+
+        (low, high)
+    }
+}
+
+impl<IA, IB> ExactSizeIterator for InterleaveIters<IA, IB>
+    where IA: ExactSizeIterator,
+        IB: ExactSizeIterator<Item=IA::Item>
+{}
+
+/// Iterates the interleaved sequence from the back, yielding exactly the reverse of the forward
+/// sequence.
+///
+/// In `Shortest` mode the forward sequence ends when the shorter iterator runs out, so any
+/// trailing elements of the longer iterator that would never be reached going forwards are
+/// skipped. Knowing how many to skip, or in `Longest` mode where the alternation ends, requires
+/// both iterators to know their exact length.
+impl<IA, IB> DoubleEndedIterator for InterleaveIters<IA, IB>
+    where IA: DoubleEndedIterator + ExactSizeIterator,
+        IB: DoubleEndedIterator<Item=IA::Item> + ExactSizeIterator
+{
+    fn next_back(&mut self) -> Option<IA::Item> {
+        let (a_len, b_len) = (self.a.len(), self.b.len());
+        let (first_len, second_len) = if self.a_next { (a_len, b_len) } else { (b_len, a_len) };
+
+        let (first_used, second_used) = if self.mode == InterleaveMode::Longest {
+            (first_len, second_len)
+        } else if first_len <= second_len {
+            (first_len, first_len)
+        } else {
+            (second_len + 1, second_len)
+        };
+
+        let (a_used, b_used) = if self.a_next { (first_used, second_used) } else { (second_used, first_used) };
+
+        for _ in a_used..a_len {
+            self.a.next_back();
+        }
+        for _ in b_used..b_len {
+            self.b.next_back();
+        }
+
+        if a_used + b_used == 0 {
+            return None;
+        }
+
+        // Whichever iterator supplies more elements supplies the last one, and the second
+        // supplies it when they're equal.
+        let last_from_first = first_used > second_used;
+        if last_from_first == self.a_next {
+            self.a.next_back()
+        } else {
+            self.b.next_back()
+        }
+    }
+}
+
+pub trait Interleave : Iterator
+{
+    fn interleave<J>(self, other: J) -> InterleaveIters<Self, J::IntoIter>
+        where Self: Sized,
+            J: IntoIterator<Item=Self::Item>
+    {
+        self.interleave_with(other, InterleaveMode::Shortest)
+    }
+
+    fn interleave_longest<J>(self, other: J) -> InterleaveIters<Self, J::IntoIter>
+        where Self: Sized,
+            J: IntoIterator<Item=Self::Item>
+    {
+        self.interleave_with(other, InterleaveMode::Longest)
+    }
+
+    fn interleave_with<J>(self, other: J, mode: InterleaveMode) -> InterleaveIters<Self, J::IntoIter>
+        where Self: Sized,
+            J: IntoIterator<Item=Self::Item>
+    {
+        InterleaveIters{ a: self, b: other.into_iter(), a_next: true, mode: mode }
+    }
+
+    /// Interleaves the two iterators as with `interleave`, converting the elements of each into
+    /// a common type. The iterators may have different element types.
+    fn interleave_into<V2, J>(self, other: J) -> InterleaveIters<AutoMapInto<V2, Self>, AutoMapInto<V2, J::IntoIter>>
+        where Self: Sized,
+            J: IntoIterator,
+            V2: From<Self::Item> + From<J::Item>
+    {
+        self.auto_map().interleave(other.into_iter().auto_map())
+    }
+
+    /// Interleaves the two iterators as with `interleave`, wrapping each element in a `Source`
+    /// recording which iterator it came from. The iterators may have different element types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_utils::iter::{Interleave, Source};
+    ///
+    /// let vals: Vec<_> = "ab".chars().interleave_tagged(1..).collect();
+    /// assert_eq!(vals, vec![Source::A('a'), Source::B(1), Source::A('b'), Source::B(2)]);
+    /// ```
+    fn interleave_tagged<J>(self, other: J) -> InterleaveIters<Map<Self, fn(Self::Item) -> Source<Self::Item, J::Item>>, Map<J::IntoIter, fn(J::Item) -> Source<Self::Item, J::Item>>>
+        where Self: Sized,
+            J: IntoIterator
+    {
+        let tag_a: fn(Self::Item) -> Source<Self::Item, J::Item> = Source::A;
+        let tag_b: fn(J::Item) -> Source<Self::Item, J::Item> = Source::B;
+
+        self.map(tag_a).interleave(other.into_iter().map(tag_b))
+    }
+}
+
+impl<T: ?Sized> Interleave for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_length() {
+        let vals: Vec<_> = vec![1, 3, 5].into_iter().interleave(vec![2, 4, 6]).collect();
+
+        assert_eq!(vals, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn first_longer() {
+        let vals: Vec<_> = vec![1, 3, 5, 7].into_iter().interleave(vec![2]).collect();
+
+        assert_eq!(vals, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn second_longer() {
+        let vals: Vec<_> = vec![1].into_iter().interleave(vec![2, 4, 6]).collect();
+
+        assert_eq!(vals, vec![1, 2]);
+    }
+
+    #[test]
+    fn size_hint() {
+        assert_eq!(vec![1, 3, 5].into_iter().interleave(vec![2, 4, 6]).len(), 6);
+        assert_eq!(vec![1, 3, 5, 7].into_iter().interleave(vec![2]).len(), 3);
+        assert_eq!(vec![1].into_iter().interleave(vec![2, 4, 6]).len(), 2);
+        assert_eq!((0..).interleave(vec![1, 2]).size_hint(), (5, Some(5)));
+    }
+
+    #[test]
+    fn rev_equal_length() {
+        let a = vec![1, 3, 5];
+        let b = vec![2, 4, 6];
+
+        let mut forward: Vec<_> = a.clone().into_iter().interleave(b.clone()).collect();
+        let backward: Vec<_> = a.into_iter().interleave(b).rev().collect();
+
+        forward.reverse();
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn rev_unequal_length() {
+        let backward: Vec<_> = vec![1, 3, 5, 7].into_iter().interleave(vec![2]).rev().collect();
+        assert_eq!(backward, vec![3, 2, 1]);
+
+        let backward: Vec<_> = vec![1].into_iter().interleave(vec![2, 4, 6]).rev().collect();
+        assert_eq!(backward, vec![2, 1]);
+    }
+
+    #[test]
+    fn both_ends() {
+        let mut iter = vec![1, 3, 5, 7].into_iter().interleave(vec![2, 4, 6, 8, 10]);
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(8));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(7));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next_back(), Some(6));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn longest() {
+        let first_longer: Vec<_> = vec![1, 3, 5, 7].into_iter().interleave_longest(vec![2]).collect();
+        let second_longer: Vec<_> = vec![1].into_iter().interleave_longest(vec![2, 4, 6]).collect();
+
+        assert_eq!(first_longer, vec![1, 2, 3, 5, 7]);
+        assert_eq!(second_longer, vec![1, 2, 4, 6]);
+    }
+
+    #[test]
+    fn modes_differ() {
+        let a = vec![1, 3, 5, 7];
+        let b = vec![2];
+
+        let shortest = a.clone().into_iter().interleave_with(b.clone(), InterleaveMode::Shortest);
+        let longest = a.into_iter().interleave_with(b, InterleaveMode::Longest);
+
+        assert_eq!(shortest.len(), 3);
+        assert_eq!(longest.len(), 5);
+        assert_eq!(shortest.count(), 3);
+        assert_eq!(longest.count(), 5);
+    }
+
+    #[test]
+    fn rev_longest() {
+        let backward: Vec<_> = vec![1, 3, 5, 7].into_iter().interleave_longest(vec![2]).rev().collect();
+        assert_eq!(backward, vec![7, 5, 3, 2, 1]);
+
+        let backward: Vec<_> = vec![1].into_iter().interleave_longest(vec![2, 4, 6]).rev().collect();
+        assert_eq!(backward, vec![6, 4, 2, 1]);
+
+        let backward: Vec<_> = vec![1, 3].into_iter().interleave_longest(vec![2, 4]).rev().collect();
+        assert_eq!(backward, vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn both_ends_longest() {
+        let mut iter = vec![1, 3].into_iter().interleave_longest(vec![2, 4, 6, 8]);
+
+        assert_eq!(iter.next_back(), Some(8));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(6));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn into_common_type() {
+        let bytes = vec![1_u8, 3, 5];
+        let words = vec![2_u16, 4, 600];
+
+        let vals: Vec<u32> = bytes.into_iter().interleave_into(words).collect();
+
+        assert_eq!(vals, vec![1, 2, 3, 4, 5, 600]);
+    }
+
+    #[test]
+    fn tagged_alternates() {
+        let vals: Vec<_> = vec!['a', 'b', 'c'].into_iter().interleave_tagged(vec![1, 2, 3]).collect();
+
+        assert_eq!(vals, vec![
+            Source::A('a'), Source::B(1),
+            Source::A('b'), Source::B(2),
+            Source::A('c'), Source::B(3),
+        ]);
+    }
+}
\ No newline at end of file
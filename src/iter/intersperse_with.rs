@@ -0,0 +1,72 @@
+/// Yields the elements of the underlying iterator, with a separator between each pair of
+/// consecutive elements. Each separator is computed from the elements either side of it.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct IntersperseWith<I: Iterator, F> {
+    iter: I,
+    f: F,
+    next_item: Option<I::Item>,
+    separator: Option<I::Item>,
+}
+
+impl<I, F> Iterator for IntersperseWith<I, F>
+    where I: Iterator,
+        F: FnMut(&I::Item, &I::Item) -> I::Item
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if let Some(sep) = self.separator.take() {
+            return Some(sep);
+        }
+
+        let cur = self.next_item.take().or_else(|| self.iter.next())?;
+
+        if let Some(following) = self.iter.next() {
+            self.separator = Some((self.f)(&cur, &following));
+            self.next_item = Some(following);
+        }
+
+        Some(cur)
+    }
+}
+
+pub trait Intersperse : Iterator
+{
+    fn intersperse_with<F>(self, f: F) -> IntersperseWith<Self, F>
+        where Self: Sized,
+            F: FnMut(&Self::Item, &Self::Item) -> Self::Item
+    {
+        IntersperseWith{ iter: self, f: f, next_item: None, separator: None }
+    }
+}
+
+impl<T: ?Sized> Intersperse for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn midpoints() {
+        let vals = vec![1.0, 3.0, 7.0];
+        let with_mids: Vec<_> = vals.into_iter().intersperse_with(|a, b| (a + b) / 2.0).collect();
+
+        assert_eq!(with_mids, vec![1.0, 2.0, 3.0, 5.0, 7.0]);
+    }
+
+    #[test]
+    fn single() {
+        let vals: Vec<_> = Some(1).into_iter().intersperse_with(|a, b| a + b).collect();
+
+        assert_eq!(vals, vec![1]);
+    }
+
+    #[test]
+    fn empty() {
+        let vals: Vec<u32> = None.into_iter().intersperse_with(|a, b| a + b).collect();
+
+        assert!(vals.is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+pub trait MinMax : Iterator
+{
+    fn min_max<V>(self) -> Option<(V, V)>
+        where Self: Sized + Iterator<Item=V>,
+            V: Ord + Clone
+    {
+        self.fold(None, |bounds, val| match bounds {
+            None => Some((val.clone(), val)),
+            Some((min, max)) => {
+                if val < min {
+                    Some((val, max))
+                } else if val > max {
+                    Some((min, val))
+                } else {
+                    Some((min, max))
+                }
+            },
+        })
+    }
+}
+
+impl<T: ?Sized> MinMax for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_max() {
+        let vals = vec![4, 2, 8, 6, 1, 9, 3];
+
+        assert_eq!(vals.into_iter().min_max(), Some((1, 9)));
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(Some("a").into_iter().min_max(), Some(("a", "a")));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(None::<u32>.into_iter().min_max(), None);
+    }
+}
\ No newline at end of file
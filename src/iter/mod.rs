@@ -1,5 +1,105 @@
 pub mod auto_map;
 pub use self::auto_map::*;
 
+#[cfg(feature = "alloc")]
 pub mod collect_map_vec;
-pub use self::collect_map_vec::*;
\ No newline at end of file
+#[cfg(feature = "alloc")]
+pub use self::collect_map_vec::*;
+
+pub mod running_spread;
+pub use self::running_spread::*;
+
+#[cfg(feature = "std")]
+pub mod counts;
+#[cfg(feature = "std")]
+pub use self::counts::*;
+
+#[cfg(feature = "alloc")]
+pub mod combinations;
+#[cfg(feature = "alloc")]
+pub use self::combinations::*;
+
+pub mod dedup_consecutive;
+pub use self::dedup_consecutive::*;
+
+pub mod run_length;
+pub use self::run_length::*;
+
+pub mod intersperse_with;
+pub use self::intersperse_with::*;
+
+pub mod pairwise;
+pub use self::pairwise::*;
+
+pub mod min_max;
+pub use self::min_max::*;
+
+pub mod take_until;
+pub use self::take_until::*;
+
+#[cfg(feature = "alloc")]
+pub mod chunked;
+#[cfg(feature = "alloc")]
+pub use self::chunked::*;
+
+#[cfg(feature = "alloc")]
+pub mod deal;
+#[cfg(feature = "alloc")]
+pub use self::deal::*;
+
+#[cfg(feature = "std")]
+pub mod timed_each;
+#[cfg(feature = "std")]
+pub use self::timed_each::*;
+
+#[cfg(feature = "alloc")]
+pub mod windows_on_trigger;
+#[cfg(feature = "alloc")]
+pub use self::windows_on_trigger::*;
+
+pub mod interleave;
+pub use self::interleave::*;
+
+#[cfg(feature = "alloc")]
+pub mod partition_map;
+#[cfg(feature = "alloc")]
+pub use self::partition_map::*;
+
+#[cfg(feature = "alloc")]
+pub mod row_numbers;
+#[cfg(feature = "alloc")]
+pub use self::row_numbers::*;
+
+pub mod enumerate_from;
+pub use self::enumerate_from::*;
+
+pub mod running_total;
+pub use self::running_total::*;
+
+#[cfg(feature = "alloc")]
+pub mod windows;
+#[cfg(feature = "alloc")]
+pub use self::windows::*;
+
+#[cfg(feature = "std")]
+pub mod flatten_sorted;
+#[cfg(feature = "std")]
+pub use self::flatten_sorted::*;
+
+#[cfg(all(feature = "rayon", feature = "std"))]
+pub mod par_group;
+#[cfg(all(feature = "rayon", feature = "std"))]
+pub use self::par_group::*;
+
+pub mod zip_longest;
+pub use self::zip_longest::*;
+
+#[cfg(feature = "alloc")]
+pub mod split_on_pred;
+#[cfg(feature = "alloc")]
+pub use self::split_on_pred::*;
+
+#[cfg(feature = "std")]
+pub mod rank_by_key;
+#[cfg(feature = "std")]
+pub use self::rank_by_key::*;
\ No newline at end of file
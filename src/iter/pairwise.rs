@@ -0,0 +1,81 @@
+/// Yields overlapping pairs of consecutive elements.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Pairwise<I: Iterator> {
+    iter: I,
+    prev: Option<I::Item>,
+}
+
+impl<I> Iterator for Pairwise<I>
+    where I: Iterator,
+        I::Item: Clone
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.prev.take().or_else(|| self.iter.next())?;
+        let second = self.iter.next()?;
+
+        self.prev = Some(second.clone());
+
+        Some((first, second))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+
+        if self.prev.is_some() {
+            (low, high)
+        } else {
+            (low.saturating_sub(1), high.map(|h| h.saturating_sub(1)))
+        }
+    }
+}
+
+pub trait Pairs : Iterator
+{
+    fn pairwise(self) -> Pairwise<Self>
+        where Self: Sized,
+            Self::Item: Clone
+    {
+        Pairwise{ iter: self, prev: None }
+    }
+}
+
+impl<T: ?Sized> Pairs for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs() {
+        let pairs: Vec<_> = vec![1, 2, 3, 4].into_iter().pairwise().collect();
+
+        assert_eq!(pairs, vec![(1, 2), (2, 3), (3, 4)]);
+    }
+
+    #[test]
+    fn deltas() {
+        let deltas: Vec<_> = vec![10, 15, 25].into_iter().pairwise().map(|(a, b)| b - a).collect();
+
+        assert_eq!(deltas, vec![5, 10]);
+    }
+
+    #[test]
+    fn too_short() {
+        assert_eq!(Some(1).into_iter().pairwise().next(), None);
+        assert_eq!(None::<u32>.into_iter().pairwise().next(), None);
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut pairs = vec![1, 2, 3, 4].into_iter().pairwise();
+        assert_eq!(pairs.size_hint(), (3, Some(3)));
+
+        pairs.next();
+        assert_eq!(pairs.size_hint(), (2, Some(2)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::cmp::Eq;
+
+use rayon::iter::ParallelIterator;
+
+pub trait ParGroupByKey : ParallelIterator
+{
+    /// Groups the items into a map by the key returned from `f`, building a map on each thread
+    /// and merging them at the end.
+    ///
+    /// For an indexed iterator, such as one over a range or `Vec`, each group keeps the
+    /// iterator's order, so the result matches `collect_map_vec_by`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate rayon;
+    /// extern crate rust_utils;
+    ///
+    /// use rayon::prelude::*;
+    /// use rust_utils::iter::ParGroupByKey;
+    ///
+    /// # fn main() {
+    /// let odd_even = (1..7).into_par_iter().par_group_by_key(|i| i % 2 == 0);
+    /// assert_eq!(odd_even[&false], vec![1, 3, 5]);
+    /// assert_eq!(odd_even[&true], vec![2, 4, 6]);
+    /// # }
+    /// ```
+    fn par_group_by_key<K, FA>(self, f: FA) -> HashMap<K, Vec<Self::Item>>
+        where Self: Sized,
+            K: Hash + Eq + Send,
+            FA: Fn(&Self::Item) -> K + Sync + Send
+    {
+        self.fold(HashMap::new, |mut map: HashMap<K, Vec<Self::Item>>, val| {
+                map.entry(f(&val)).or_insert(vec![]).push(val);
+                map
+            })
+            .reduce(HashMap::new, |mut map, other| {
+                for (key, mut vals) in other {
+                    map.entry(key).or_insert(vec![]).append(&mut vals);
+                }
+
+                map
+            })
+    }
+}
+
+impl<T: ?Sized> ParGroupByKey for T where T: ParallelIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iter::CollectMapVec;
+    use rayon::prelude::*;
+
+    #[test]
+    fn matches_sequential() {
+        let parallel = (0_u32..100_000).into_par_iter().par_group_by_key(|i| i % 7);
+        let sequential = (0_u32..100_000).collect_map_vec_by(|i| i % 7);
+
+        assert_eq!(parallel, sequential);
+    }
+}
\ No newline at end of file
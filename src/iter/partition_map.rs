@@ -0,0 +1,47 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+pub trait PartitionMap : Iterator
+{
+    fn partition_map<V, A, B, F>(self, f: F) -> (Vec<A>, Vec<B>)
+        where Self: Sized + Iterator<Item=V>,
+            F: Fn(V) -> Result<A, B>
+    {
+        let mut oks = vec![];
+        let mut errs = vec![];
+
+        for val in self {
+            match f(val) {
+                Ok(a) => oks.push(a),
+                Err(b) => errs.push(b),
+            }
+        }
+
+        (oks, errs)
+    }
+}
+
+impl<T: ?Sized> PartitionMap for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_and_text() {
+        let strings = vec!["1", "foo", "22", "bar", "3"];
+        let (numbers, text) = strings.into_iter().partition_map(|s| s.parse::<u32>().map_err(|_| s));
+
+        assert_eq!(numbers, vec![1, 22, 3]);
+        assert_eq!(text, vec!["foo", "bar"]);
+    }
+
+    #[test]
+    fn empty() {
+        let (oks, errs): (Vec<u32>, Vec<()>) = None.into_iter().partition_map(Ok);
+
+        assert!(oks.is_empty());
+        assert!(errs.is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::cmp::Eq;
+
+/// Pairs each element with the number of earlier elements that had the same key.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RankByKey<I, K, F> {
+    iter: I,
+    f: F,
+    seen: HashMap<K, usize>,
+}
+
+impl<I, K, F> Iterator for RankByKey<I, K, F>
+    where I: Iterator,
+        K: Hash + Eq,
+        F: Fn(&I::Item) -> K
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = self.iter.next()?;
+
+        let seen = self.seen.entry((self.f)(&val)).or_insert(0);
+        let rank = *seen;
+        *seen += 1;
+
+        Some((rank, val))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait RankBy : Iterator
+{
+    /// Pairs each element with its position among the elements with the same key, so the first
+    /// element with each key is paired with 0, the second with 1, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_utils::iter::RankBy;
+    ///
+    /// let ranks: Vec<_> = vec![1, 2, 3, 4].into_iter().rank_by_key(|i| i % 2).collect();
+    /// assert_eq!(ranks, vec![(0, 1), (0, 2), (1, 3), (1, 4)]);
+    /// ```
+    fn rank_by_key<K, F>(self, f: F) -> RankByKey<Self, K, F>
+        where Self: Sized,
+            K: Hash + Eq,
+            F: Fn(&Self::Item) -> K
+    {
+        RankByKey {
+            iter: self,
+            f: f,
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<T: ?Sized> RankBy for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let ranks: Vec<_> = vec!["a", "b", "a", "a", "b"].into_iter().rank_by_key(|&s| s).collect();
+
+        assert_eq!(ranks, vec![(0, "a"), (0, "b"), (1, "a"), (2, "a"), (1, "b")]);
+    }
+
+    #[test]
+    fn lazy() {
+        let mut ranks = (0..).rank_by_key(|i| i % 3);
+
+        assert_eq!(ranks.nth(4), Some((1, 4)));
+        assert_eq!(ranks.next(), Some((1, 5)));
+        assert_eq!(ranks.next(), Some((2, 6)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use core::fmt::Display;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use super::{EnumerateFrom, EnumerateStart};
+
+/// Yields each element formatted as a CSV-style row prefixed with its row number, such as
+/// `"1,value"`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct WithRowNumbers<I> {
+    iter: EnumerateFrom<I>,
+}
+
+impl<I> Iterator for WithRowNumbers<I>
+    where I: Iterator,
+        I::Item: Display
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.iter.next().map(|(row, val)| format!("{},{}", row, val))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait RowNumbers : Iterator
+{
+    fn with_row_numbers(self, start: usize) -> WithRowNumbers<Self>
+        where Self: Sized,
+            Self::Item: Display
+    {
+        WithRowNumbers{ iter: self.enumerate_from(start) }
+    }
+}
+
+impl<T: ?Sized> RowNumbers for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows() {
+        let rows: Vec<_> = vec!["foo", "bar", "baz"].into_iter().with_row_numbers(1).collect();
+
+        assert_eq!(rows, vec!["1,foo", "2,bar", "3,baz"]);
+    }
+
+    #[test]
+    fn rows_from_offset() {
+        let rows: Vec<_> = vec![1.5, 2.25].into_iter().with_row_numbers(10).collect();
+
+        assert_eq!(rows, vec!["10,1.5", "11,2.25"]);
+    }
+}
\ No newline at end of file
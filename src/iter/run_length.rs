@@ -0,0 +1,157 @@
+/// Yields each run of consecutive equal elements as the first element of the run, and the length
+/// of the run.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct RunLengthEncode<I: Iterator> {
+    iter: I,
+    pending: Option<I::Item>,
+}
+
+impl<I> Iterator for RunLengthEncode<I>
+    where I: Iterator,
+        I::Item: PartialEq
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur = self.pending.take().or_else(|| self.iter.next())?;
+        let mut count = 1;
+
+        while let Some(val) = self.iter.next() {
+            if val != cur {
+                self.pending = Some(val);
+                break;
+            }
+
+            count += 1;
+        }
+
+        Some((cur, count))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.is_some() as usize;
+        let (low, high) = self.iter.size_hint();
+        let low = if low > 0 || pending > 0 { 1 } else { 0 };
+
+        (low, high.and_then(|h| h.checked_add(pending)))
+    }
+}
+
+/// Expands each `(value, count)` pair into `count` copies of `value`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct RunLengthDecode<I, V> {
+    iter: I,
+    current: Option<(V, usize)>,
+}
+
+impl<I, V> Iterator for RunLengthDecode<I, V>
+    where I: Iterator<Item=(V, usize)>,
+        V: Clone
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<V> {
+        loop {
+            match self.current.take() {
+                Some((_, 0)) => {},
+                Some((val, 1)) => return Some(val),
+                Some((val, count)) => {
+                    self.current = Some((val.clone(), count - 1));
+                    return Some(val);
+                },
+                None => self.current = Some(self.iter.next()?),
+            }
+        }
+    }
+}
+
+pub trait RunLengths : Iterator
+{
+    fn run_length_encode(self) -> RunLengthEncode<Self>
+        where Self: Sized,
+            Self::Item: PartialEq
+    {
+        RunLengthEncode{ iter: self, pending: None }
+    }
+
+    fn run_length(self) -> RunLengthEncode<Self>
+        where Self: Sized,
+            Self::Item: PartialEq
+    {
+        self.run_length_encode()
+    }
+
+    fn run_length_decode<V>(self) -> RunLengthDecode<Self, V>
+        where Self: Sized + Iterator<Item=(V, usize)>,
+            V: Clone
+    {
+        RunLengthDecode{ iter: self, current: None }
+    }
+}
+
+impl<T: ?Sized> RunLengths for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chars() {
+        let runs: Vec<_> = "aaabbc".chars().run_length().collect();
+
+        assert_eq!(runs, vec![('a', 3), ('b', 2), ('c', 1)]);
+    }
+
+    #[test]
+    fn encode() {
+        let runs: Vec<_> = vec!["a", "a", "b"].into_iter().run_length_encode().collect();
+
+        assert_eq!(runs, vec![("a", 2), ("b", 1)]);
+    }
+
+    #[test]
+    fn encode_repeated_runs() {
+        let runs: Vec<_> = vec![1, 1, 2, 1, 1, 1].into_iter().run_length_encode().collect();
+
+        assert_eq!(runs, vec![(1, 2), (2, 1), (1, 3)]);
+    }
+
+    #[test]
+    fn decode() {
+        let vals: Vec<_> = vec![("a", 2), ("b", 1)].into_iter().run_length_decode().collect();
+
+        assert_eq!(vals, vec!["a", "a", "b"]);
+    }
+
+    #[test]
+    fn decode_zero_count() {
+        let vals: Vec<_> = vec![(1, 0), (2, 2), (3, 0)].into_iter().run_length_decode().collect();
+
+        assert_eq!(vals, vec![2, 2]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let decoded: String = "aaabbc".chars().run_length_encode().run_length_decode().collect();
+
+        assert_eq!(decoded, "aaabbc");
+    }
+
+    #[test]
+    fn single() {
+        let runs: Vec<_> = Some(5).into_iter().run_length().collect();
+
+        assert_eq!(runs, vec![(5, 1)]);
+    }
+
+    #[test]
+    fn empty() {
+        let runs: Vec<_> = "".chars().run_length().collect();
+
+        assert!(runs.is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,83 @@
+use core::ops::Sub;
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct RunningSpread<I: Iterator> {
+    iter: I,
+    bounds: Option<(I::Item, I::Item)>,
+}
+
+impl<I> Iterator for RunningSpread<I>
+    where I: Iterator,
+        I::Item: Ord + Clone + Sub<Output=I::Item>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let val = self.iter.next()?;
+
+        let (min, max) = match self.bounds.take() {
+            Some((min, max)) => {
+                if val < min {
+                    (val, max)
+                } else if val > max {
+                    (min, val)
+                } else {
+                    (min, max)
+                }
+            },
+            None => (val.clone(), val),
+        };
+
+        let spread = max.clone() - min.clone();
+        self.bounds = Some((min, max));
+
+        Some(spread)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait Spread : Iterator
+{
+    fn running_spread(self) -> RunningSpread<Self>
+        where Self: Sized,
+            Self::Item: Ord + Clone + Sub<Output=Self::Item>
+    {
+        RunningSpread{ iter: self, bounds: None }
+    }
+}
+
+impl<T: ?Sized> Spread for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spread() {
+        let vals = vec![3, 1, 4];
+        let spread: Vec<_> = vals.into_iter().running_spread().collect();
+
+        assert_eq!(spread, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn spread_new_min_and_max() {
+        let vals = vec![5, 5, 7, 2, 6, 10, 1];
+        let spread: Vec<_> = vals.into_iter().running_spread().collect();
+
+        assert_eq!(spread, vec![0, 0, 2, 5, 5, 8, 9]);
+    }
+
+    #[test]
+    fn spread_empty() {
+        let spread: Vec<i32> = Vec::new().into_iter().running_spread().collect();
+
+        assert!(spread.is_empty());
+    }
+}
\ No newline at end of file
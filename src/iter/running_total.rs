@@ -0,0 +1,108 @@
+use core::ops::Add;
+
+/// Yields the accumulator after folding in each element of the underlying iterator.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct RunningFold<I, Acc, F> {
+    iter: I,
+    acc: Option<Acc>,
+    f: F,
+}
+
+impl<I, Acc, F> Iterator for RunningFold<I, Acc, F>
+    where I: Iterator,
+        Acc: Clone,
+        F: FnMut(Acc, I::Item) -> Acc
+{
+    type Item = Acc;
+
+    fn next(&mut self) -> Option<Acc> {
+        let val = self.iter.next()?;
+        let acc = self.acc.take().expect("accumulator is always present between calls");
+
+        let acc = (self.f)(acc, val);
+        self.acc = Some(acc.clone());
+
+        Some(acc)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Yields the prefix sums of the underlying iterator.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct RunningSum<I: Iterator> {
+    inner: RunningFold<I, I::Item, fn(I::Item, I::Item) -> I::Item>,
+}
+
+impl<I> Iterator for RunningSum<I>
+    where I: Iterator,
+        I::Item: Add<Output=I::Item> + Clone
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+fn add<V: Add<Output=V>>(a: V, b: V) -> V {
+    a + b
+}
+
+pub trait RunningTotal : Iterator
+{
+    fn running_fold<Acc, F>(self, init: Acc, f: F) -> RunningFold<Self, Acc, F>
+        where Self: Sized,
+            Acc: Clone,
+            F: FnMut(Acc, Self::Item) -> Acc
+    {
+        RunningFold{ iter: self, acc: Some(init), f: f }
+    }
+
+    fn running_sum(self) -> RunningSum<Self>
+        where Self: Sized,
+            Self::Item: Add<Output=Self::Item> + Clone + Default
+    {
+        RunningSum{ inner: self.running_fold(Self::Item::default(), add as fn(Self::Item, Self::Item) -> Self::Item) }
+    }
+}
+
+impl<T: ?Sized> RunningTotal for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_sum() {
+        let sums: Vec<_> = vec![1, 2, 3].into_iter().running_sum().collect();
+
+        assert_eq!(sums, vec![1, 3, 6]);
+    }
+
+    #[test]
+    fn running_sum_empty() {
+        let sums: Vec<_> = Vec::<u32>::new().into_iter().running_sum().collect();
+
+        assert_eq!(sums, Vec::<u32>::new());
+    }
+
+    #[test]
+    fn running_fold() {
+        let products: Vec<_> = vec![1, 2, 3, 4].into_iter().running_fold(1, |acc, v| acc * v).collect();
+
+        assert_eq!(products, vec![1, 2, 6, 24]);
+    }
+}
\ No newline at end of file
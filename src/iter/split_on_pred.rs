@@ -0,0 +1,96 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Splits the elements into groups, starting a new group at each element matching the
+/// predicate. The matching elements themselves are dropped.
+///
+/// As with `slice::split`, a boundary at either end, or two adjacent boundaries, produce an
+/// empty group.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SplitOnPred<I, P> {
+    iter: I,
+    pred: P,
+    finished: bool,
+}
+
+impl<I, P> Iterator for SplitOnPred<I, P>
+    where I: Iterator,
+        P: FnMut(&I::Item) -> bool
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let mut group = vec![];
+        for val in &mut self.iter {
+            if (self.pred)(&val) {
+                return Some(group);
+            }
+
+            group.push(val);
+        }
+
+        self.finished = true;
+        Some(group)
+    }
+}
+
+pub trait SplitOn : Iterator
+{
+    /// Groups the elements, starting a new group at each element for which `pred` returns
+    /// `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_utils::iter::SplitOn;
+    ///
+    /// let lines = vec!["a", "b", "", "c"];
+    /// let paragraphs: Vec<_> = lines.into_iter().split_on_pred(|l| l.is_empty()).collect();
+    /// assert_eq!(paragraphs, vec![vec!["a", "b"], vec!["c"]]);
+    /// ```
+    fn split_on_pred<P>(self, pred: P) -> SplitOnPred<Self, P>
+        where Self: Sized,
+            P: FnMut(&Self::Item) -> bool
+    {
+        SplitOnPred {
+            iter: self,
+            pred: pred,
+            finished: false,
+        }
+    }
+}
+
+impl<T: ?Sized> SplitOn for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_on_zero() {
+        let groups: Vec<_> = vec![1, 2, 0, 3, 4, 0, 5].into_iter().split_on_pred(|&i| i == 0).collect();
+
+        assert_eq!(groups, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn boundaries_at_ends() {
+        let groups: Vec<_> = vec![0, 1, 0, 0].into_iter().split_on_pred(|&i| i == 0).collect();
+        let empty: Vec<u32> = vec![];
+
+        assert_eq!(groups, vec![empty.clone(), vec![1], empty.clone(), empty]);
+    }
+
+    #[test]
+    fn no_boundaries() {
+        let groups: Vec<_> = vec![1, 2, 3].into_iter().split_on_pred(|&i| i == 0).collect();
+
+        assert_eq!(groups, vec![vec![1, 2, 3]]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,165 @@
+/// Yields elements up to and including the first one matching the predicate.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TakeUntil<I, P> {
+    iter: I,
+    pred: P,
+    done: bool,
+}
+
+impl<I, P> Iterator for TakeUntil<I, P>
+    where I: Iterator,
+        P: FnMut(&I::Item) -> bool
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+
+        let val = self.iter.next()?;
+        self.done = (self.pred)(&val);
+
+        Some(val)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (low, high) = self.iter.size_hint();
+            (low.min(1), high)
+        }
+    }
+}
+
+/// Yields elements while the running total of their weights stays below a limit.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TakeUntilSum<I, F> {
+    iter: I,
+    weight: F,
+    limit: f64,
+    total: f64,
+    inclusive: bool,
+    done: bool,
+}
+
+impl<I, F> Iterator for TakeUntilSum<I, F>
+    where I: Iterator,
+        F: Fn(&I::Item) -> f64
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+
+        let val = self.iter.next()?;
+        self.total += (self.weight)(&val);
+
+        if self.total < self.limit {
+            Some(val)
+        } else {
+            self.done = true;
+            if self.inclusive { Some(val) } else { None }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, high) = self.iter.size_hint();
+            (0, high)
+        }
+    }
+}
+
+pub trait Until : Iterator
+{
+    fn take_until<P>(self, pred: P) -> TakeUntil<Self, P>
+        where Self: Sized,
+            P: FnMut(&Self::Item) -> bool
+    {
+        TakeUntil{ iter: self, pred: pred, done: false }
+    }
+
+    /// Yields elements while the running total of `weight` stays below `limit`, stopping before
+    /// the element that brings the total up to or past the limit.
+    fn take_until_sum<F>(self, limit: f64, weight: F) -> TakeUntilSum<Self, F>
+        where Self: Sized,
+            F: Fn(&Self::Item) -> f64
+    {
+        TakeUntilSum{ iter: self, weight: weight, limit: limit, total: 0.0, inclusive: false, done: false }
+    }
+
+    /// As `take_until_sum`, but also yields the element that brings the total up to or past the
+    /// limit.
+    fn take_until_sum_inclusive<F>(self, limit: f64, weight: F) -> TakeUntilSum<Self, F>
+        where Self: Sized,
+            F: Fn(&Self::Item) -> f64
+    {
+        TakeUntilSum{ iter: self, weight: weight, limit: limit, total: 0.0, inclusive: true, done: false }
+    }
+}
+
+impl<T: ?Sized> Until for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn includes_terminator() {
+        let vals: Vec<_> = vec![1, 2, 3, 4].into_iter().take_until(|&x| x == 3).collect();
+
+        assert_eq!(vals, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn differs_from_take_while() {
+        let until: Vec<_> = (1..).take_until(|&x| x >= 3).collect();
+        let take_while: Vec<_> = (1..).take_while(|&x| x < 3).collect();
+
+        assert_eq!(until, vec![1, 2, 3]);
+        assert_eq!(take_while, vec![1, 2]);
+    }
+
+    #[test]
+    fn never_matches() {
+        let vals: Vec<_> = vec![1, 2].into_iter().take_until(|&x| x == 3).collect();
+
+        assert_eq!(vals, vec![1, 2]);
+    }
+
+    #[test]
+    fn until_sum_exclusive() {
+        let at_limit: Vec<_> = vec![1, 2, 3, 4].into_iter().take_until_sum(3.0, |&x| x as f64).collect();
+        let past_limit: Vec<_> = vec![1, 2, 3, 4].into_iter().take_until_sum(3.5, |&x| x as f64).collect();
+
+        assert_eq!(at_limit, vec![1]);
+        assert_eq!(past_limit, vec![1, 2]);
+    }
+
+    #[test]
+    fn until_sum_inclusive() {
+        let at_limit: Vec<_> = vec![1, 2, 3, 4].into_iter().take_until_sum_inclusive(3.0, |&x| x as f64).collect();
+        let past_limit: Vec<_> = vec![1, 2, 3, 4].into_iter().take_until_sum_inclusive(3.5, |&x| x as f64).collect();
+
+        assert_eq!(at_limit, vec![1, 2]);
+        assert_eq!(past_limit, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn until_sum_never_reached() {
+        let vals: Vec<_> = vec![1, 2].into_iter().take_until_sum(10.0, |&x| x as f64).collect();
+
+        assert_eq!(vals, vec![1, 2]);
+    }
+}
\ No newline at end of file
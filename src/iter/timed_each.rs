@@ -0,0 +1,85 @@
+use std::time::{Duration, Instant};
+
+/// Passes through the elements of the underlying iterator, reporting how long the underlying
+/// iterator took to produce each one.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TimedEach<I, F, C> {
+    iter: I,
+    sink: F,
+    clock: C,
+}
+
+impl<I, F, C> Iterator for TimedEach<I, F, C>
+    where I: Iterator,
+        F: FnMut(Duration),
+        C: FnMut() -> Instant
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let start = (self.clock)();
+        let val = self.iter.next()?;
+        let end = (self.clock)();
+
+        (self.sink)(end.duration_since(start));
+
+        Some(val)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait Timed : Iterator
+{
+    fn timed_each<F>(self, sink: F) -> TimedEach<Self, F, fn() -> Instant>
+        where Self: Sized,
+            F: FnMut(Duration)
+    {
+        self.timed_each_with_clock(sink, Instant::now)
+    }
+
+    fn timed_each_with_clock<F, C>(self, sink: F, clock: C) -> TimedEach<Self, F, C>
+        where Self: Sized,
+            F: FnMut(Duration),
+            C: FnMut() -> Instant
+    {
+        TimedEach{ iter: self, sink: sink, clock: clock }
+    }
+}
+
+impl<T: ?Sized> Timed for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use super::*;
+
+    #[test]
+    fn mocked_clock() {
+        let base = Instant::now();
+        let mut ticks = vec![0, 5, 5, 12, 20, 21, 30].into_iter()
+            .map(|ms| base + Duration::from_millis(ms));
+
+        let mut times = vec![];
+        let vals: Vec<_> = (1..4)
+            .timed_each_with_clock(|d| times.push(d), || ticks.next().unwrap())
+            .collect();
+
+        assert_eq!(vals, vec![1, 2, 3]);
+        assert_eq!(times, vec![Duration::from_millis(5), Duration::from_millis(7), Duration::from_millis(1)]);
+    }
+
+    #[test]
+    fn real_clock() {
+        let mut count = 0;
+        let vals: Vec<_> = (1..4).timed_each(|_| count += 1).collect();
+
+        assert_eq!(vals, vec![1, 2, 3]);
+        assert_eq!(count, 3);
+    }
+}
\ No newline at end of file
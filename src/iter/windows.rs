@@ -0,0 +1,105 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Yields overlapping windows of a fixed size over the elements of the underlying iterator, as
+/// with the `windows` method on slices.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Windows<I: Iterator> {
+    iter: I,
+    size: usize,
+    window: VecDeque<I::Item>,
+}
+
+impl<I> Iterator for Windows<I>
+    where I: Iterator,
+        I::Item: Clone
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < self.size {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        Some(self.window.iter().cloned().collect())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+
+        // Once the window is full, each new element makes one more window.
+        let missing = self.size - self.window.len();
+        let windows = |n: usize| if missing == 0 { n } else { n.saturating_sub(missing - 1) };
+
+        (windows(low), high.map(windows))
+    }
+}
+
+pub trait Windowed : Iterator
+{
+    fn windows(self, size: usize) -> Windows<Self>
+        where Self: Sized,
+            Self::Item: Clone
+    {
+        assert!(size != 0, "window size must be non-zero");
+
+        // The window can't hold more elements than the iterator has.
+        let capacity = size.min(self.size_hint().0);
+        Windows{ iter: self, size: size, window: VecDeque::with_capacity(capacity) }
+    }
+}
+
+impl<T: ?Sized> Windowed for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs() {
+        let windows: Vec<_> = vec![1, 2, 3, 4].into_iter().windows(2).collect();
+
+        assert_eq!(windows, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+    }
+
+    #[test]
+    fn shorter_than_size() {
+        let windows: Vec<_> = vec![1, 2].into_iter().windows(3).collect();
+
+        assert_eq!(windows, Vec::<Vec<u32>>::new());
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut windows = (1..6).windows(3);
+        assert_eq!(windows.size_hint(), (3, Some(3)));
+
+        windows.next();
+        assert_eq!(windows.size_hint(), (2, Some(2)));
+        assert_eq!((1..3).windows(3).size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        let _ = (1..6).windows(0);
+    }
+
+    #[test]
+    fn huge_size() {
+        let mut windows = vec![1, 2, 3].into_iter().windows(usize::max_value());
+
+        assert_eq!(windows.next(), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Whenever an element matches the trigger, yields the elements that preceded it.
+///
+/// Up to `window` preceding elements are yielded, oldest first. If fewer than `window` elements
+/// came before the trigger, all of them are yielded. The triggering element itself is not part
+/// of the window, but is included in the windows of later triggers.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct WindowsOnTrigger<I: Iterator, F> {
+    iter: I,
+    trigger: F,
+    window: usize,
+    buffer: VecDeque<I::Item>,
+}
+
+impl<I, F> Iterator for WindowsOnTrigger<I, F>
+    where I: Iterator,
+        I::Item: Clone,
+        F: FnMut(&I::Item) -> bool
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(val) = self.iter.next() {
+            let triggered = (self.trigger)(&val);
+            let window = if triggered {
+                Some(self.buffer.iter().cloned().collect())
+            } else {
+                None
+            };
+
+            if self.window > 0 {
+                if self.buffer.len() == self.window {
+                    self.buffer.pop_front();
+                }
+                self.buffer.push_back(val);
+            }
+
+            if window.is_some() {
+                return window;
+            }
+        }
+
+        None
+    }
+}
+
+pub trait TriggeredWindows : Iterator
+{
+    fn windows_on_trigger<F>(self, window: usize, trigger: F) -> WindowsOnTrigger<Self, F>
+        where Self: Sized,
+            Self::Item: Clone,
+            F: FnMut(&Self::Item) -> bool
+    {
+        // The buffer can't hold more elements than the iterator has.
+        let capacity = window.min(self.size_hint().0);
+
+        WindowsOnTrigger {
+            iter: self,
+            trigger: trigger,
+            window: window,
+            buffer: VecDeque::with_capacity(capacity),
+        }
+    }
+}
+
+impl<T: ?Sized> TriggeredWindows for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_before() {
+        let vals = vec![1, 2, 3, 0, 4, 5, 6, 0];
+        let windows: Vec<_> = vals.into_iter().windows_on_trigger(2, |&i| i == 0).collect();
+
+        assert_eq!(windows, vec![vec![2, 3], vec![5, 6]]);
+    }
+
+    #[test]
+    fn short_history() {
+        let vals = vec![1, 0, 0, 2];
+        let windows: Vec<_> = vals.into_iter().windows_on_trigger(3, |&i| i == 0).collect();
+
+        assert_eq!(windows, vec![vec![1], vec![1, 0]]);
+    }
+
+    #[test]
+    fn zero_window() {
+        let vals = vec![0, 1, 0];
+        let windows: Vec<_> = vals.into_iter().windows_on_trigger(0, |&i| i == 0).collect();
+
+        assert_eq!(windows, vec![Vec::<i32>::new(), vec![]]);
+    }
+
+    #[test]
+    fn huge_window() {
+        let vals = vec![1, 2, 0];
+        let windows: Vec<_> = vals.into_iter().windows_on_trigger(usize::max_value(), |&i| i == 0).collect();
+
+        assert_eq!(windows, vec![vec![1, 2]]);
+    }
+}
\ No newline at end of file
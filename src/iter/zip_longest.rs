@@ -0,0 +1,98 @@
+use core::cmp;
+use core::iter::Fuse;
+
+/// Zips two iterators together until both are exhausted, yielding `None` for the side that
+/// ran out first.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct ZipLongestIters<IA, IB> {
+    a: Fuse<IA>,
+    b: Fuse<IB>,
+}
+
+impl<IA, IB> Iterator for ZipLongestIters<IA, IB>
+    where IA: Iterator,
+        IB: Iterator
+{
+    type Item = (Option<IA::Item>, Option<IB::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (None, None) => None,
+            pair => Some(pair),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_low, a_high) = self.a.size_hint();
+        let (b_low, b_high) = self.b.size_hint();
+
+        let high = match (a_high, b_high) {
+            (Some(a), Some(b)) => Some(cmp::max(a, b)),
+            _ => None,
+        };
+
+        (cmp::max(a_low, b_low), high)
+    }
+}
+
+pub trait ZipLongest : Iterator
+{
+    /// Zips with `other` until both iterators are exhausted. Once one side runs out, `None` is
+    /// yielded in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_utils::iter::ZipLongest;
+    ///
+    /// let pairs: Vec<_> = vec![1, 2, 3].into_iter().zip_longest(vec!['a']).collect();
+    /// assert_eq!(pairs, vec![(Some(1), Some('a')), (Some(2), None), (Some(3), None)]);
+    /// ```
+    fn zip_longest<J>(self, other: J) -> ZipLongestIters<Self, J::IntoIter>
+        where Self: Sized,
+            J: IntoIterator
+    {
+        ZipLongestIters {
+            a: self.fuse(),
+            b: other.into_iter().fuse(),
+        }
+    }
+}
+
+impl<T: ?Sized> ZipLongest for T
+    where T: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_longer() {
+        let pairs: Vec<_> = vec![1, 2, 3].into_iter().zip_longest(vec![4]).collect();
+
+        assert_eq!(pairs, vec![(Some(1), Some(4)), (Some(2), None), (Some(3), None)]);
+    }
+
+    #[test]
+    fn second_longer() {
+        let pairs: Vec<_> = vec![1].into_iter().zip_longest(vec!["a", "b"]).collect();
+
+        assert_eq!(pairs, vec![(Some(1), Some("a")), (None, Some("b"))]);
+    }
+
+    #[test]
+    fn equal_length() {
+        let iter = vec![1, 2].into_iter().zip_longest(vec![3, 4]);
+
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(Some(1), Some(3)), (Some(2), Some(4))]);
+    }
+
+    #[test]
+    fn both_empty() {
+        let empty: Vec<u32> = vec![];
+
+        assert_eq!(empty.iter().zip_longest(empty.iter()).next(), None);
+    }
+}
\ No newline at end of file
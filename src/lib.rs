@@ -3,11 +3,32 @@
 #![feature(inclusive_range)]
 #![feature(try_from)]
 #![feature(inclusive_range_syntax)]
+#![feature(i128_type)]
+#![cfg_attr(all(feature = "alloc", not(feature = "std")), feature(alloc))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-#[cfg(feature = "sem_string")]
-extern crate itertools;
+// `no_std` already links core, so it only needs declaring when building with std.
+#[cfg(any(feature = "std", test))]
+extern crate core;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[macro_use]
+extern crate alloc;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "indexmap")]
+extern crate indexmap;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
 
 pub mod iter;
+#[cfg(feature = "std")]
 pub mod text;
+#[cfg(feature = "std")]
 pub mod slice;
+#[cfg(feature = "std")]
 pub mod time;
\ No newline at end of file
@@ -0,0 +1,48 @@
+pub trait CumMax
+{
+    fn cummax_in_place(&mut self);
+}
+
+impl<T: Ord + Copy> CumMax for [T]
+{
+    fn cummax_in_place(&mut self) {
+        let mut max = match self.first() {
+            Some(&first) => first,
+            None => return,
+        };
+
+        for i in self.iter_mut() {
+            max = max.max(*i);
+            *i = max;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_max() {
+        let mut vals = vec![1, 3, 2, 5, 4];
+        vals.cummax_in_place();
+
+        assert_eq!(vals, vec![1, 3, 3, 5, 5]);
+    }
+
+    #[test]
+    fn decreasing_tail() {
+        let mut vals = vec![2, 7, 6, 5, 4, 3];
+        vals.cummax_in_place();
+
+        assert_eq!(vals, vec![2, 7, 7, 7, 7, 7]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut vals: Vec<u32> = vec![];
+        vals.cummax_in_place();
+
+        assert!(vals.is_empty());
+    }
+}
\ No newline at end of file
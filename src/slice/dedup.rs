@@ -0,0 +1,69 @@
+/// Moves the first element of each run of consecutive elements with equal keys to the front of
+/// the slice, in order, and returns how many there are. The rest of the slice is left in an
+/// unspecified order, so the caller can then `truncate` a `Vec` to the returned length.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::dedup_adjacent_by_key;
+///
+/// let mut vals = vec![10, 11, 20, 21, 22, 10];
+/// let len = dedup_adjacent_by_key(&mut vals, |v| v / 10);
+/// vals.truncate(len);
+/// assert_eq!(vals, vec![10, 20, 10]);
+/// ```
+pub fn dedup_adjacent_by_key<T, K, F>(slice: &mut [T], mut key: F) -> usize
+    where K: PartialEq,
+        F: FnMut(&T) -> K
+{
+    if slice.is_empty() {
+        return 0;
+    }
+
+    let mut retained = 1;
+    for i in 1..slice.len() {
+        if key(&slice[i]) != key(&slice[retained - 1]) {
+            slice.swap(retained, i);
+            retained += 1;
+        }
+    }
+
+    retained
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs() {
+        let mut vals = [1, 1, 2, 3, 3, 3, 1];
+        let len = dedup_adjacent_by_key(&mut vals, |&v| v);
+
+        assert_eq!(len, 4);
+        assert_eq!(vals[..len], [1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn keeps_first_of_run() {
+        let mut vals = ["apple", "avocado", "banana", "blueberry", "cherry"];
+        let len = dedup_adjacent_by_key(&mut vals, |s| s.chars().next());
+
+        assert_eq!(vals[..len], ["apple", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn no_duplicates() {
+        let mut vals = [1, 2, 3];
+
+        assert_eq!(dedup_adjacent_by_key(&mut vals, |&v| v), 3);
+        assert_eq!(vals, [1, 2, 3]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut vals: [u32; 0] = [];
+
+        assert_eq!(dedup_adjacent_by_key(&mut vals, |&v| v), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::ops::{Add, Mul};
+
+pub trait Dot<T>
+{
+    fn dot(&self, other: &[T]) -> T;
+}
+
+impl<T> Dot<T> for [T]
+    where T: Mul<Output=T> + Add<Output=T> + Copy + Default
+{
+    fn dot(&self, other: &[T]) -> T {
+        assert_eq!(self.len(), other.len(), "dot product requires slices of equal length");
+
+        self.iter().zip(other.iter())
+            .fold(T::default(), |acc, (&a, &b)| acc + a * b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints() {
+        assert_eq!([1, 2, 3].dot(&[4, 5, 6]), 32);
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!([0.5, 2.0].dot(&[4.0, 0.25]), 2.5);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: [u32; 0] = [];
+
+        assert_eq!(empty.dot(&[]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "dot product requires slices of equal length")]
+    fn length_mismatch() {
+        [1, 2, 3].dot(&[1, 2]);
+    }
+}
\ No newline at end of file
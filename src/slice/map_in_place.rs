@@ -0,0 +1,37 @@
+/// Replaces each element of the slice with the result of calling `f` on it.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::map_in_place;
+///
+/// let mut vals = [1, 2, 3];
+/// map_in_place(&mut vals, |i| i * 2);
+/// assert_eq!(vals, [2, 4, 6]);
+/// ```
+pub fn map_in_place<T, F: FnMut(&T) -> T>(slice: &mut [T], mut f: F) {
+    for i in slice.iter_mut() {
+        *i = f(i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double() {
+        let mut vals = vec![1, 2, 3];
+        map_in_place(&mut vals, |i| i * 2);
+
+        assert_eq!(vals, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn non_copy() {
+        let mut vals = vec!["a".to_owned(), "b".to_owned()];
+        map_in_place(&mut vals[1..], |s| s.repeat(3));
+
+        assert_eq!(vals, vec!["a", "bbb"]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+use std::cmp::Ordering;
+
+pub trait Median<T>
+{
+    fn median(&mut self) -> Option<T>;
+}
+
+impl<T: Ord + Copy> Median<T> for [T]
+{
+    /// Returns the median of the slice, or the lower of the two middle elements if the length is
+    /// even. Returns `None` if the slice is empty.
+    ///
+    /// This uses quickselect rather than sorting, so runs in linear time on average, but leaves
+    /// the slice partially reordered.
+    fn median(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mid = (self.len() - 1) / 2;
+        select_nth(self, mid);
+
+        Some(self[mid])
+    }
+}
+
+/// Reorders the slice so that the element at `n` is the one that would be there if the slice
+/// were sorted, with no larger elements before it and no smaller elements after it.
+fn select_nth<T: Ord>(slice: &mut [T], n: usize) {
+    let (mut lo, mut hi) = (0, slice.len());
+
+    while hi - lo > 1 {
+        // Partition around the middle element, which avoids the worst case on sorted input.
+        // Elements equal to the pivot are gathered in `lt..gt`, so runs of duplicates are
+        // settled in a single pass.
+        let mid = lo + (hi - lo) / 2;
+        slice.swap(lo, mid);
+
+        let (mut lt, mut i, mut gt) = (lo, lo + 1, hi);
+        while i < gt {
+            match slice[i].cmp(&slice[lt]) {
+                Ordering::Less => {
+                    slice.swap(i, lt);
+                    lt += 1;
+                    i += 1;
+                },
+                Ordering::Greater => {
+                    gt -= 1;
+                    slice.swap(i, gt);
+                },
+                Ordering::Equal => i += 1,
+            }
+        }
+
+        if n < lt {
+            hi = lt;
+        } else if n >= gt {
+            lo = gt;
+        } else {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn odd() {
+        let mut vals = vec![9, 1, 7, 3, 5];
+
+        assert_eq!(vals.median(), Some(5));
+    }
+
+    #[test]
+    fn even() {
+        let mut vals = vec![8, 2, 6, 4];
+
+        assert_eq!(vals.median(), Some(4));
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!([3].median(), Some(3));
+    }
+
+    #[test]
+    fn empty() {
+        let mut vals: Vec<u32> = vec![];
+
+        assert_eq!(vals.median(), None);
+    }
+
+    #[test]
+    fn matches_sorting() {
+        let vals = vec![5, 3, 3, 9, 0, 7, 3, 1, 8, 8, 2];
+
+        for len in 1..vals.len() + 1 {
+            let mut sorted = vals[..len].to_vec();
+            sorted.sort();
+
+            assert_eq!(vals[..len].to_vec().median(), Some(sorted[(len - 1) / 2]), "len = {}", len);
+        }
+    }
+
+    #[test]
+    fn many_duplicates() {
+        let mut vals = vec![7; 100_000];
+        assert_eq!(vals.median(), Some(7));
+
+        let vals: Vec<_> = (0..100_000).map(|i| (i * 7919) % 3).collect();
+        let mut sorted = vals.clone();
+        sorted.sort();
+
+        assert_eq!(vals.clone().median(), Some(sorted[(vals.len() - 1) / 2]));
+    }
+}
\ No newline at end of file
@@ -1,2 +1,35 @@
 pub mod set_range;
-pub use self::set_range::*;
\ No newline at end of file
+pub use self::set_range::*;
+
+pub mod normalize;
+pub use self::normalize::*;
+
+pub mod dot;
+pub use self::dot::*;
+
+pub mod map_in_place;
+pub use self::map_in_place::*;
+
+pub mod shift;
+pub use self::shift::*;
+
+pub mod search;
+pub use self::search::*;
+
+pub mod cummax;
+pub use self::cummax::*;
+
+pub mod median;
+pub use self::median::*;
+
+pub mod swap_ranges;
+pub use self::swap_ranges::*;
+
+pub mod pairs_mut;
+pub use self::pairs_mut::*;
+
+pub mod reverse_range;
+pub use self::reverse_range::*;
+
+pub mod dedup;
+pub use self::dedup::*;
\ No newline at end of file
@@ -0,0 +1,46 @@
+pub trait Normalize
+{
+    fn normalize_to_sum(&mut self, target: f64);
+}
+
+impl Normalize for [f64]
+{
+    fn normalize_to_sum(&mut self, target: f64) {
+        let sum: f64 = self.iter().sum();
+        if sum == 0.0 {
+            return;
+        }
+
+        let scale = target / sum;
+        self.iter_mut().for_each(|i| *i *= scale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_one() {
+        let mut vals = vec![1.0, 1.0, 2.0];
+        vals.normalize_to_sum(1.0);
+
+        assert_eq!(vals, vec![0.25, 0.25, 0.5]);
+    }
+
+    #[test]
+    fn to_target() {
+        let mut vals = vec![1.0, 3.0];
+        vals[..].normalize_to_sum(8.0);
+
+        assert_eq!(vals, vec![2.0, 6.0]);
+    }
+
+    #[test]
+    fn all_zero() {
+        let mut vals = vec![0.0; 3];
+        vals.normalize_to_sum(1.0);
+
+        assert_eq!(vals, vec![0.0, 0.0, 0.0]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+/// Calls `f` on each overlapping pair of adjacent elements, `(slice[i], slice[i + 1])`, in order.
+///
+/// Because the pairs overlap, each element other than the first and last is passed to `f` twice,
+/// and the second call sees any changes made by the first.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::for_each_pair_mut;
+///
+/// let mut vals = [1, 2, 3];
+/// for_each_pair_mut(&mut vals, |a, b| *b += *a);
+/// assert_eq!(vals, [1, 3, 6]);
+/// ```
+pub fn for_each_pair_mut<T, F: FnMut(&mut T, &mut T)>(slice: &mut [T], mut f: F) {
+    for i in 1..slice.len() {
+        let (left, right) = slice.split_at_mut(i);
+        f(&mut left[i - 1], &mut right[0]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn average_neighbours() {
+        let mut vals = [0.0, 4.0, 8.0];
+        for_each_pair_mut(&mut vals, |a, b| {
+            let avg = (*a + *b) / 2.0;
+            *a = avg;
+            *b = avg;
+        });
+
+        assert_eq!(vals, [2.0, 5.0, 5.0]);
+    }
+
+    #[test]
+    fn too_short() {
+        let mut calls = 0;
+        for_each_pair_mut(&mut [1], |_, _| calls += 1);
+        for_each_pair_mut(&mut Vec::<u32>::new(), |_, _| calls += 1);
+
+        assert_eq!(calls, 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+use std::ops::Range;
+
+/// Reverses the order of the elements in the given range of the slice, leaving the rest of the
+/// slice untouched.
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::reverse_range;
+///
+/// let mut vals = [1, 2, 3, 4, 5];
+/// reverse_range(&mut vals, 1..4);
+/// assert_eq!(vals, [1, 4, 3, 2, 5]);
+/// ```
+pub fn reverse_range<T>(slice: &mut [T], range: Range<usize>) {
+    slice[range].reverse();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn middle() {
+        let mut vals = [1, 2, 3, 4, 5];
+        reverse_range(&mut vals, 1..4);
+
+        assert_eq!(vals, [1, 4, 3, 2, 5]);
+    }
+
+    #[test]
+    fn empty_and_single() {
+        let mut vals = [1, 2, 3];
+        reverse_range(&mut vals, 1..1);
+        reverse_range(&mut vals, 2..3);
+
+        assert_eq!(vals, [1, 2, 3]);
+    }
+
+    #[test]
+    fn rotate_by_reversals() {
+        let mut vals = [1, 2, 3, 4, 5];
+        reverse_range(&mut vals, 0..2);
+        reverse_range(&mut vals, 2..5);
+        reverse_range(&mut vals, 0..5);
+
+        assert_eq!(vals, [3, 4, 5, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        reverse_range(&mut [1, 2, 3], 1..4);
+    }
+}
\ No newline at end of file
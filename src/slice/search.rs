@@ -0,0 +1,95 @@
+/// Returns the index of the first occurrence of `needle` within `haystack`, or `None` if it
+/// doesn't occur. An empty needle matches at index 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::find_subslice;
+///
+/// assert_eq!(find_subslice(&[1, 2, 3, 4], &[3, 4]), Some(2));
+/// assert_eq!(find_subslice(&[1, 2, 3, 4], &[4, 3]), None);
+/// ```
+pub fn find_subslice<T: PartialEq>(haystack: &[T], needle: &[T]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Splits a partitioned slice into the leading elements matching `pred` and the rest, using a
+/// binary search to find the first element that doesn't match.
+///
+/// The slice must be partitioned, with every element matching `pred` before every element that
+/// doesn't. If it isn't, the split point is unspecified.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::split_on;
+///
+/// let (low, high) = split_on(&[1, 3, 4, 6, 9], |&x| x < 5);
+/// assert_eq!(low, [1, 3, 4]);
+/// assert_eq!(high, [6, 9]);
+/// ```
+pub fn split_on<T, F: FnMut(&T) -> bool>(slice: &[T], mut pred: F) -> (&[T], &[T]) {
+    let (mut low, mut high) = (0, slice.len());
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(&slice[mid]) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    slice.split_at(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn present() {
+        assert_eq!(find_subslice(&[1, 2, 3, 4, 5], &[3, 4]), Some(2));
+        assert_eq!(find_subslice(&[1, 2, 3], &[1, 2, 3]), Some(0));
+    }
+
+    #[test]
+    fn absent() {
+        assert_eq!(find_subslice(&[1, 2, 3, 4, 5], &[4, 3]), None);
+        assert_eq!(find_subslice(&[1, 2], &[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn empty_needle() {
+        assert_eq!(find_subslice(&[1, 2, 3], &[]), Some(0));
+        assert_eq!(find_subslice::<u32>(&[], &[]), Some(0));
+    }
+
+    #[test]
+    fn overlapping() {
+        assert_eq!(find_subslice(&[1, 1, 1], &[1, 1]), Some(0));
+        assert_eq!(find_subslice(&[1, 2, 1, 2, 1, 3], &[1, 2, 1, 3]), Some(2));
+    }
+
+    #[test]
+    fn split_partitioned() {
+        let vals = [1, 2, 4, 5, 7, 8];
+        let (low, high) = split_on(&vals, |&x| x < 5);
+
+        assert_eq!(low, [1, 2, 4]);
+        assert_eq!(high, [5, 7, 8]);
+    }
+
+    #[test]
+    fn split_all_or_none() {
+        let vals = [1, 2, 3];
+
+        assert_eq!(split_on(&vals, |&x| x < 5), (&vals[..], &[][..]));
+        assert_eq!(split_on(&vals, |&x| x > 5), (&[][..], &vals[..]));
+        assert_eq!(split_on(&[] as &[u32], |&x| x < 5), (&[][..], &[][..]));
+    }
+}
\ No newline at end of file
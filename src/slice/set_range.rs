@@ -1,6 +1,11 @@
 pub trait SetRange<T>
 {
     fn set(&mut self, v: T);
+
+    fn clamp_all(&mut self, min: T, max: T)
+        where T: PartialOrd;
+
+    fn try_set_from_slice(&mut self, src: &[T]) -> Result<(), String>;
 }
 
 impl<'a, T: Copy> SetRange<T> for [T]
@@ -8,6 +13,29 @@ impl<'a, T: Copy> SetRange<T> for [T]
     fn set(&mut self, v: T) {
         self.iter_mut().for_each(|i| *i = v);
     }
+
+    fn clamp_all(&mut self, min: T, max: T)
+        where T: PartialOrd
+    {
+        assert!(min <= max, "clamp_all called with min greater than max");
+
+        for i in self.iter_mut() {
+            if *i < min {
+                *i = min;
+            } else if *i > max {
+                *i = max;
+            }
+        }
+    }
+
+    fn try_set_from_slice(&mut self, src: &[T]) -> Result<(), String> {
+        if self.len() != src.len() {
+            return Err(format!("source length {} does not match destination length {}", src.len(), self.len()));
+        }
+
+        self.copy_from_slice(src);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -21,4 +49,44 @@ mod tests {
 
         assert_eq!(vals, vec![0,2,2,0,0]);
     }
+
+    #[test]
+    fn clamp() {
+        let mut vals = vec![-1, 5, 3];
+        vals.clamp_all(0, 4);
+
+        assert_eq!(vals, vec![0, 4, 3]);
+    }
+
+    #[test]
+    fn clamp_floats() {
+        let mut vals = vec![-0.5, 0.25, 1.5];
+        vals.clamp_all(0.0, 1.0);
+
+        assert_eq!(vals, vec![0.0, 0.25, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp_all called with min greater than max")]
+    fn clamp_inverted_bounds() {
+        let mut vals = vec![1, 2, 3];
+        vals.clamp_all(4, 0);
+    }
+
+    #[test]
+    fn try_set_matching() {
+        let mut vals = vec![0; 5];
+        assert_eq!(vals[1..4].try_set_from_slice(&[1, 2, 3]), Ok(()));
+
+        assert_eq!(vals, vec![0,1,2,3,0]);
+    }
+
+    #[test]
+    fn try_set_mismatched() {
+        let mut vals = vec![0; 3];
+        let res = vals.try_set_from_slice(&[1, 2]);
+
+        assert_eq!(res, Err("source length 2 does not match destination length 3".to_owned()));
+        assert_eq!(vals, vec![0,0,0]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,84 @@
+/// Shifts the elements of the slice left by `by` positions, filling the vacated positions at the
+/// end with `fill`. Shifting by the length of the slice or more fills the entire slice.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::shift_fill;
+///
+/// let mut vals = [1, 2, 3, 4, 5];
+/// shift_fill(&mut vals, 2, 0);
+/// assert_eq!(vals, [3, 4, 5, 0, 0]);
+/// ```
+pub fn shift_fill<T: Copy>(slice: &mut [T], by: usize, fill: T) {
+    let by = by.min(slice.len());
+    let len = slice.len();
+
+    for i in by..len {
+        slice[i - by] = slice[i];
+    }
+    slice[len - by..].iter_mut().for_each(|i| *i = fill);
+}
+
+/// Shifts the elements of the slice right by `by` positions, filling the vacated positions at the
+/// start with `fill`. Shifting by the length of the slice or more fills the entire slice.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::shift_fill_right;
+///
+/// let mut vals = [1, 2, 3, 4, 5];
+/// shift_fill_right(&mut vals, 2, 0);
+/// assert_eq!(vals, [0, 0, 1, 2, 3]);
+/// ```
+pub fn shift_fill_right<T: Copy>(slice: &mut [T], by: usize, fill: T) {
+    let by = by.min(slice.len());
+
+    for i in (by..slice.len()).rev() {
+        slice[i] = slice[i - by];
+    }
+    slice[..by].iter_mut().for_each(|i| *i = fill);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn left() {
+        let mut vals = vec![1, 2, 3, 4, 5];
+        shift_fill(&mut vals, 2, 0);
+
+        assert_eq!(vals, vec![3, 4, 5, 0, 0]);
+    }
+
+    #[test]
+    fn right() {
+        let mut vals = vec![1, 2, 3, 4, 5];
+        shift_fill_right(&mut vals, 2, 0);
+
+        assert_eq!(vals, vec![0, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn zero() {
+        let mut vals = vec![1, 2, 3];
+        shift_fill(&mut vals, 0, 0);
+        shift_fill_right(&mut vals, 0, 0);
+
+        assert_eq!(vals, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn past_end() {
+        let mut left = vec![1, 2, 3];
+        shift_fill(&mut left, 10, 9);
+
+        let mut right = vec![1, 2, 3];
+        shift_fill_right(&mut right, 10, 9);
+
+        assert_eq!(left, vec![9, 9, 9]);
+        assert_eq!(right, vec![9, 9, 9]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+use std::mem;
+use std::ops::Range;
+
+/// Swaps the elements in two equal-length, non-overlapping ranges of the slice.
+///
+/// # Panics
+///
+/// Panics if the ranges differ in length, overlap, or are out of bounds.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::slice::swap_ranges;
+///
+/// let mut vals = [1, 2, 3, 4, 5];
+/// swap_ranges(&mut vals, 0..2, 3..5);
+/// assert_eq!(vals, [4, 5, 3, 1, 2]);
+/// ```
+pub fn swap_ranges<T>(slice: &mut [T], a: Range<usize>, b: Range<usize>) {
+    assert_eq!(a.len(), b.len(), "swap_ranges requires ranges of equal length");
+    assert!(a.end <= b.start || b.end <= a.start, "swap_ranges requires non-overlapping ranges");
+
+    let (first, second) = if a.start <= b.start { (a, b) } else { (b, a) };
+    let len = second.end - second.start;
+    let (left, right) = slice.split_at_mut(second.start);
+
+    for (x, y) in left[first].iter_mut().zip(right[..len].iter_mut()) {
+        mem::swap(x, y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap() {
+        let mut vals = [1, 2, 3, 4, 5];
+        swap_ranges(&mut vals, 0..2, 3..5);
+
+        assert_eq!(vals, [4, 5, 3, 1, 2]);
+    }
+
+    #[test]
+    fn swap_reversed_order() {
+        let mut vals = [1, 2, 3, 4, 5];
+        swap_ranges(&mut vals, 3..5, 0..2);
+
+        assert_eq!(vals, [4, 5, 3, 1, 2]);
+    }
+
+    #[test]
+    fn adjacent() {
+        let mut vals = [1, 2, 3, 4];
+        swap_ranges(&mut vals, 0..2, 2..4);
+
+        assert_eq!(vals, [3, 4, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_ranges requires ranges of equal length")]
+    fn unequal_length() {
+        swap_ranges(&mut [1, 2, 3, 4, 5], 0..1, 3..5);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_ranges requires non-overlapping ranges")]
+    fn overlapping() {
+        swap_ranges(&mut [1, 2, 3, 4, 5], 0..3, 2..5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn second_range_out_of_bounds() {
+        let mut vals = [1, 2, 3, 4, 5];
+        swap_ranges(&mut vals, 0..2, 4..6);
+    }
+}
\ No newline at end of file
@@ -1,12 +1,33 @@
 use std::ops::{Range, RangeInclusive};
 use std::collections::range::RangeArgument;
 use std::convert::TryFrom;
+use std::fmt;
+
+#[cfg(feature = "rand")]
+use rand::Rng;
 
 pub trait RangeMarker {}
 
 impl<T> RangeMarker for Range<T> {}
 impl<T> RangeMarker for RangeInclusive<T> {}
 
+const SURROGATE_START: u32 = 0xD800;
+const SURROGATE_END: u32 = 0xDFFF;
+const SURROGATE_START_PREV: u32 = SURROGATE_START - 1;
+const SURROGATE_END_NEXT: u32 = SURROGATE_END + 1;
+
+const UNICODE_BLOCKS: &[(&str, char, char)] = &[
+    ("BasicLatin", '\u{0000}', '\u{007F}'),
+    ("Latin1Supplement", '\u{0080}', '\u{00FF}'),
+    ("LatinExtendedA", '\u{0100}', '\u{017F}'),
+    ("Greek", '\u{0370}', '\u{03FF}'),
+    ("Cyrillic", '\u{0400}', '\u{04FF}'),
+    ("Hebrew", '\u{0590}', '\u{05FF}'),
+    ("Arabic", '\u{0600}', '\u{06FF}'),
+    ("Hiragana", '\u{3040}', '\u{309F}'),
+    ("Katakana", '\u{30A0}', '\u{30FF}'),
+];
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct CharIter {
     start: u32,
@@ -18,37 +39,134 @@ impl CharIter {
         use std::collections::Bound::*;
 
         let start = match r.start() {
-            Included(&s) => s as u32,
-            Excluded(&s) => CharIter::prev_char(s) as u32,
+            Included(&s) => Some(s),
+            Excluded(&s) => CharIter::next_char(s),
             Unbounded => unreachable!(),
         };
 
         let end = match r.end() {
-            Included(&s) => s as u32,
-            Excluded(&s) => CharIter::prev_char(s) as u32,
+            Included(&s) => Some(s),
+            Excluded(&s) => CharIter::prev_char(s),
             Unbounded => unreachable!(),
         };
 
-        CharIter {
-            start: start,
-            end: end
+        match (start, end) {
+            (Some(start), Some(end)) => CharIter {
+                start: start as u32,
+                end: end as u32
+            },
+            _ => CharIter::empty(),
         }
     }
 
-    fn prev_char(c: char) -> char {
-        let next = (0..c as u32).rev()
-            .filter_map(|c| char::try_from(c).ok())
-            .next();
+    /// Returns an iterator over every char between `a` and `b` inclusive, in ascending order
+    /// whichever order they are given in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_utils::text::CharIter;
+    ///
+    /// let chars: String = CharIter::between('E', 'A').collect();
+    /// assert_eq!(chars, "ABCDE");
+    /// ```
+    pub fn between(a: char, b: char) -> CharIter {
+        if a <= b {
+            CharIter::new(a..=b)
+        } else {
+            CharIter::new(b..=a)
+        }
+    }
 
-        next.unwrap()
+    /// Returns an iterator over the Unicode block with the given name, or `None` if the name is
+    /// not recognised.
+    ///
+    /// Only a small set of common blocks are known: `BasicLatin`, `Latin1Supplement`,
+    /// `LatinExtendedA`, `Greek`, `Cyrillic`, `Hebrew`, `Arabic`, `Hiragana`, and `Katakana`.
+    pub fn block(name: &str) -> Option<CharIter> {
+        UNICODE_BLOCKS.iter()
+            .find(|&&(block, _, _)| block == name)
+            .map(|&(_, start, end)| CharIter::new(start..=end))
     }
 
-    fn next_char(c: char) -> char {
-        let next = (c as u32+1..)
-            .filter_map(|c| char::try_from(c).ok())
-            .next();
+    /// Returns an iterator yielding the first char, then every `step`th valid char after it.
+    ///
+    /// Surrogate code points are not chars, so they are not counted towards the step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn step_by_chars(self, step: usize) -> StepByChars {
+        assert!(step != 0, "step_by_chars called with a step of zero");
+
+        StepByChars {
+            iter: self,
+            step: step,
+            first: true,
+        }
+    }
 
-        next.unwrap()
+    /// Returns how many chars are left in the range, without consuming the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_utils::text::CharIter;
+    ///
+    /// let mut chars = CharIter::new('A'..='E');
+    /// chars.next();
+    /// assert_eq!(chars.chars_remaining(), 4);
+    /// ```
+    pub fn chars_remaining(&self) -> usize {
+        if self.start > self.end {
+            return 0;
+        }
+
+        let mut count = self.end - self.start + 1;
+        if self.start < SURROGATE_START && self.end > SURROGATE_END {
+            count -= SURROGATE_END - SURROGATE_START + 1;
+        }
+
+        count as usize
+    }
+
+    /// Returns a random char from the remaining range, with every char equally likely.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    #[cfg(feature = "rand")]
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> char {
+        let remaining = self.chars_remaining();
+        assert!(remaining != 0, "sample called on an empty range");
+
+        self.clone().nth(rng.gen_range(0, remaining)).unwrap()
+    }
+
+    fn empty() -> CharIter {
+        CharIter {
+            start: 1,
+            end: 0,
+        }
+    }
+
+    /// Returns the char before `c`, skipping over the surrogate code points, or `None` if `c`
+    /// is the first char.
+    fn prev_char(c: char) -> Option<char> {
+        match c as u32 {
+            0 => None,
+            SURROGATE_END_NEXT => char::try_from(SURROGATE_START - 1).ok(),
+            c => char::try_from(c - 1).ok(),
+        }
+    }
+
+    /// Returns the char after `c`, skipping over the surrogate code points, or `None` if `c`
+    /// is the last char.
+    fn next_char(c: char) -> Option<char> {
+        match c as u32 {
+            SURROGATE_START_PREV => char::try_from(SURROGATE_END + 1).ok(),
+            c => char::try_from(c + 1).ok(),
+        }
     }
 }
 
@@ -62,7 +180,43 @@ impl Iterator for CharIter {
 
         let cur = char::try_from(self.start).unwrap();
 
-        self.start = CharIter::next_char(cur) as u32;
+        // There may be no char after the last one, such as with `char::MAX`, so the iterator is
+        // emptied instead of stepping past it.
+        if self.start == self.end {
+            *self = CharIter::empty();
+        } else {
+            self.start = CharIter::next_char(cur).unwrap() as u32;
+        }
+
+        Some(cur)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.chars_remaining();
+        (remaining, Some(remaining))
+    }
+
+    /// Jumps directly to the target char rather than stepping through the range, skipping
+    /// over the surrogate code points.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let mut target = self.start as u64 + n as u64;
+        if self.start < SURROGATE_START && target >= SURROGATE_START as u64 {
+            target += (SURROGATE_END - SURROGATE_START + 1) as u64;
+        }
+
+        if self.start > self.end || target > self.end as u64 {
+            *self = CharIter::empty();
+            return None;
+        }
+
+        let cur = char::try_from(target as u32).unwrap();
+
+        if target == self.end as u64 {
+            *self = CharIter::empty();
+        } else {
+            self.start = CharIter::next_char(cur).unwrap() as u32;
+        }
 
         Some(cur)
     }
@@ -76,24 +230,155 @@ impl DoubleEndedIterator for CharIter {
 
         let cur = char::try_from(self.end).unwrap();
 
-        self.end = CharIter::prev_char(cur) as u32;
+        if self.start == self.end {
+            *self = CharIter::empty();
+        } else {
+            self.end = CharIter::prev_char(cur).unwrap() as u32;
+        }
 
         Some(cur)
     }
 }
 
+/// Writes the remaining chars in the range, so `to_string` collects them without consuming the
+/// iterator.
+impl fmt::Display for CharIter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use std::fmt::Write;
+
+        for c in self.clone() {
+            f.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct StepByChars {
+    iter: CharIter,
+    step: usize,
+    first: bool,
+}
+
+impl Iterator for StepByChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first {
+            self.first = false;
+            self.iter.next()
+        } else {
+            self.iter.nth(self.step - 1)
+        }
+    }
+}
+
+/// A set of chars made up of several ranges, such as `[a-zA-Z0-9]`.
+///
+/// Overlapping and adjacent ranges are merged, and the ranges are iterated in ascending order.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::text::{CharClass, CharIter};
+///
+/// let class = CharClass::new(vec![CharIter::new('a'..='c'), CharIter::new('0'..='1')]);
+/// assert!(class.contains('b'));
+/// assert!(!class.contains('d'));
+/// assert_eq!(class.iter().collect::<String>(), "01abc");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CharClass {
+    ranges: Vec<CharIter>,
+}
+
+impl CharClass {
+    pub fn new(ranges: Vec<CharIter>) -> CharClass {
+        let mut ranges: Vec<_> = ranges.into_iter()
+            .filter(|r| r.start <= r.end)
+            .collect();
+        ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<CharIter> = Vec::with_capacity(ranges.len());
+        for r in ranges {
+            match merged.last_mut() {
+                Some(ref mut last) if r.start <= last.end + 1 => {
+                    last.end = last.end.max(r.end);
+                    continue;
+                },
+                _ => {},
+            }
+
+            merged.push(r);
+        }
+
+        CharClass {
+            ranges: merged,
+        }
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        self.ranges.iter().any(|r| r.start <= c && c <= r.end)
+    }
+
+    pub fn iter(&self) -> CharClassIter {
+        self.clone().into_iter()
+    }
+}
+
+impl IntoIterator for CharClass {
+    type Item = char;
+    type IntoIter = CharClassIter;
+
+    fn into_iter(self) -> CharClassIter {
+        CharClassIter {
+            ranges: self.ranges,
+            index: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CharClassIter {
+    ranges: Vec<CharIter>,
+    index: usize,
+}
+
+impl Iterator for CharClassIter {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(range) = self.ranges.get_mut(self.index) {
+            if let Some(c) = range.next() {
+                return Some(c);
+            }
+
+            self.index += 1;
+        }
+
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn prev_char() {
-        assert_eq!('A', CharIter::prev_char('B'))
+        assert_eq!(Some('A'), CharIter::prev_char('B'));
+        assert_eq!(Some('\u{D7FF}'), CharIter::prev_char('\u{E000}'));
+        assert_eq!(None, CharIter::prev_char('\0'));
     }
 
     #[test]
     fn next_char() {
-        assert_eq!('B', CharIter::next_char('A'))
+        assert_eq!(Some('B'), CharIter::next_char('A'));
+        assert_eq!(Some('\u{E000}'), CharIter::next_char('\u{D7FF}'));
+        assert_eq!(None, CharIter::next_char(std::char::MAX));
     }
 
     #[test]
@@ -135,4 +420,185 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn rev_mirrors_across_surrogates() {
+        let chars = CharIter::new('\u{D7F0}'..='\u{E010}');
+
+        let mut forward: Vec<_> = chars.clone().collect();
+        let backward: Vec<_> = chars.rev().collect();
+
+        assert_eq!(forward.len(), 33);
+        assert_eq!(backward.len(), 33);
+        forward.reverse();
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn both_ends_across_surrogates() {
+        let mut chars = CharIter::new('\u{D7FF}'..='\u{E000}');
+
+        assert_eq!(chars.next_back(), Some('\u{E000}'));
+        assert_eq!(chars.next(), Some('\u{D7FF}'));
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.next_back(), None);
+    }
+
+    #[test]
+    fn range_limits() {
+        let last: Vec<_> = CharIter::new('\u{10FFFE}'..=std::char::MAX).collect();
+        let first: Vec<_> = CharIter::new('\0'..='\u{1}').rev().collect();
+
+        assert_eq!(last, vec!['\u{10FFFE}', std::char::MAX]);
+        assert_eq!(first, vec!['\u{1}', '\0']);
+        assert_eq!(CharIter::new('\0'..'\0').count(), 0);
+    }
+
+    #[test]
+    fn nth_matches_stepping() {
+        let chars = CharIter::new('\u{D700}'..='\u{E100}');
+
+        for n in (0..0x200).chain(0xF8..0x108).chain(0x900..0x910) {
+            assert_eq!(chars.clone().nth(n), chars.clone().skip(n).next(), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn nth_past_end() {
+        let mut chars = CharIter::new('A'..='E');
+
+        assert_eq!(chars.nth(5), None);
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn nth_continues() {
+        let mut chars = CharIter::new('A'..='E');
+
+        assert_eq!(chars.nth(1), Some('B'));
+        assert_eq!(chars.nth(1), Some('D'));
+        assert_eq!(chars.next(), Some('E'));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn to_string() {
+        let chars = CharIter::new('a'..='e');
+
+        assert_eq!(chars.to_string(), "abcde");
+        assert_eq!(chars.collect::<String>(), "abcde");
+    }
+
+    #[test]
+    fn between() {
+        assert_eq!(CharIter::between('E', 'A'), CharIter::between('A', 'E'));
+        assert_eq!(CharIter::between('E', 'A').collect::<String>(), "ABCDE");
+        assert_eq!(CharIter::between('x', 'x').collect::<String>(), "x");
+    }
+
+    #[test]
+    fn block_known() {
+        assert_eq!(CharIter::block("BasicLatin").unwrap().count(), 128);
+        assert_eq!(CharIter::block("Cyrillic").unwrap().count(), 256);
+
+        let greek: Vec<_> = CharIter::block("Greek").unwrap().collect();
+        assert_eq!(greek.len(), 144);
+        assert!(greek.contains(&'λ'));
+    }
+
+    #[test]
+    fn block_unknown() {
+        assert_eq!(CharIter::block("Klingon"), None);
+    }
+
+    #[test]
+    fn step_by_chars() {
+        let expected = vec!['A', 'C', 'E', 'G', 'I'];
+        let actual: Vec<_> = CharIter::new('A'..='J').step_by_chars(2).collect();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn step_by_chars_skips_surrogates() {
+        let actual: Vec<_> = CharIter::new('\u{D7FF}'..='\u{E001}').step_by_chars(2).collect();
+
+        assert_eq!(actual, vec!['\u{D7FF}', '\u{E001}']);
+    }
+
+    #[test]
+    #[should_panic(expected = "step_by_chars called with a step of zero")]
+    fn step_by_chars_zero() {
+        let _ = CharIter::new('A'..='J').step_by_chars(0);
+    }
+
+    #[test]
+    fn char_class_letters() {
+        let class = CharClass::new(vec![CharIter::new('a'..='z'), CharIter::new('A'..='Z')]);
+
+        assert_eq!(class.iter().count(), 52);
+        assert!(class.contains('q'));
+        assert!(class.contains('Q'));
+        assert!(!class.contains('['));
+    }
+
+    #[test]
+    fn char_class_merges_overlaps() {
+        let class = CharClass::new(vec![
+            CharIter::new('f'..='z'),
+            CharIter::new('a'..='m'),
+            CharIter::new('0'..'0'),
+        ]);
+
+        assert_eq!(class.iter().count(), 26);
+        assert_eq!(class, CharClass::new(vec![CharIter::new('a'..='z')]));
+    }
+
+    #[test]
+    fn chars_remaining() {
+        let mut chars = CharIter::new('A'..='E');
+        chars.next();
+        chars.next();
+
+        assert_eq!(chars.chars_remaining(), 3);
+        assert_eq!(chars.size_hint(), (3, Some(3)));
+
+        chars.next_back();
+        assert_eq!(chars.chars_remaining(), 2);
+
+        chars.by_ref().for_each(drop);
+        assert_eq!(chars.chars_remaining(), 0);
+    }
+
+    #[test]
+    fn chars_remaining_across_surrogates() {
+        let chars = CharIter::new('\u{D7F0}'..='\u{E010}');
+
+        assert_eq!(chars.chars_remaining(), 33);
+        assert_eq!(CharIter::new('\0'..=std::char::MAX).chars_remaining(), 0x10FFFF + 1 - 0x800);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample_within_range() {
+        use rand::{SeedableRng, StdRng};
+
+        let mut rng = StdRng::from_seed(&[42_usize][..]);
+        let chars = CharIter::new('\u{D7F0}'..='\u{E010}');
+
+        for _ in 0..1000 {
+            let c = chars.sample(&mut rng);
+            assert!(('\u{D7F0}' <= c && c <= '\u{D7FF}') || ('\u{E000}' <= c && c <= '\u{E010}'), "{:?}", c);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    #[should_panic(expected = "sample called on an empty range")]
+    fn sample_empty() {
+        use rand::{SeedableRng, StdRng};
+
+        let mut rng = StdRng::from_seed(&[42_usize][..]);
+        let _ = CharIter::new('a'..'a').sample(&mut rng);
+    }
 }
\ No newline at end of file
@@ -2,53 +2,199 @@ use std::convert::From;
 use std::cmp::Ordering;
 use std::ops::Deref;
 
-#[cfg(feature = "sem_string")]
-use itertools::Itertools;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Debug, Eq, PartialEq)]
-enum StringPart<'a> {
+/// A segment of a string, as compared by `SemanticString`.
+#[derive(Debug, Clone, Copy)]
+pub enum SemanticPart<'a> {
     Text(&'a str),
-    Number(u64),
+    /// A run of ASCII digits. This is kept as text rather than parsed, so numbers of any length
+    /// can be compared.
+    Number(&'a str),
+    /// A signed or decimal number, only produced by `SemanticString::new_signed_decimal`. This
+    /// is also kept as text, so it can be compared exactly with other numbers.
+    Float(&'a str),
 }
 
-impl<'a> Ord for StringPart<'a> {
+impl<'a> SemanticPart<'a> {
+    fn as_f64(&self) -> Option<f64> {
+        match *self {
+            SemanticPart::Text(_) => None,
+            SemanticPart::Number(n) => n.parse().ok(),
+            SemanticPart::Float(n) => n.parse().ok(),
+        }
+    }
+
+    fn cmp_case_insensitive(&self, other: &Self) -> Ordering {
+        use self::SemanticPart::*;
+        match (self, other) {
+            (&Text(a), &Text(b)) => {
+                let a = a.chars().flat_map(char::to_lowercase);
+                let b = b.chars().flat_map(char::to_lowercase);
+                a.cmp(b)
+            },
+            _ => self.cmp(other),
+        }
+    }
+}
+
+impl<'a> Ord for SemanticPart<'a> {
     fn cmp(&self, other: &Self) -> Ordering {
-        use self::StringPart::*;
+        use self::SemanticPart::*;
         match (self, other) {
             (&Text(ref a), &Text(ref b)) => a.cmp(b),
-            (&Number(ref a), &Number(ref b)) => a.cmp(b),
-            (&Text(_), &Number(_)) => Ordering::Less,
-            (&Number(_), &Text(_)) => Ordering::Greater,
+            (&Number(a), &Number(b)) => cmp_digits(a, b),
+            // Numbers sort before text, as digits do in ASCII.
+            (&Text(_), _) => Ordering::Greater,
+            (_, &Text(_)) => Ordering::Less,
+            (&Number(a), &Float(b)) | (&Float(a), &Number(b)) | (&Float(a), &Float(b)) => cmp_numbers(a, b),
         }
     }
 }
 
-impl<'a> PartialOrd for StringPart<'a> {
+/// Compares two numbers written as an optional `-`, digits, and an optional decimal point
+/// followed by more digits, by their exact value.
+fn cmp_numbers(a: &str, b: &str) -> Ordering {
+    // Splits a number into whether it's negative, and its integer and fractional digits.
+    fn split(s: &str) -> (bool, &str, &str) {
+        let negative = s.starts_with('-');
+        let digits = if negative { &s[1..] } else { s };
+        let (int, frac) = match digits.find('.') {
+            Some(point) => (&digits[..point], &digits[point + 1..]),
+            None => (digits, ""),
+        };
+
+        // Negative zero is the same as zero.
+        let frac = &frac[..frac.rfind(|c| c != '0').map_or(0, |last| last + 1)];
+        let is_zero = int.chars().all(|c| c == '0') && frac.is_empty();
+
+        (negative && !is_zero, int, frac)
+    }
+
+    let (a_neg, a_int, a_frac) = split(a);
+    let (b_neg, b_int, b_frac) = split(b);
+
+    // Trailing zeros have been removed, so the fractional digits compare lexically.
+    let magnitude = cmp_digits(a_int, b_int).then_with(|| a_frac.cmp(b_frac));
+
+    match (a_neg, b_neg) {
+        (false, false) => magnitude,
+        (true, true) => magnitude.reverse(),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+    }
+}
+
+/// Compares two runs of digits by value, by comparing the number of significant digits and then
+/// the digits themselves.
+fn cmp_digits(a: &str, b: &str) -> Ordering {
+    fn significant(s: &str) -> &str {
+        s.find(|c| c != '0').map_or("", |start| &s[start..])
+    }
+
+    let (a, b) = (significant(a), significant(b));
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+impl<'a> PartialEq for SemanticPart<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for SemanticPart<'a> {}
+
+impl<'a> PartialOrd for SemanticPart<'a> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+/// Splits a string into alternating runs of text and ASCII digits.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct SemanticParts<'a> {
+    raw: &'a str,
+}
+
+impl<'a> SemanticParts<'a> {
+    /// Returns the next part along with the text it was parsed from.
+    fn next_with_text(&mut self) -> Option<(SemanticPart<'a>, &'a str)> {
+        let is_num = self.raw.chars().next()?.is_ascii_digit();
+        let end = self.raw.find(|c: char| c.is_ascii_digit() != is_num).unwrap_or(self.raw.len());
+
+        let part = &self.raw[..end];
+        self.raw = &self.raw[end..];
+
+        if is_num {
+            Some((SemanticPart::Number(part), part))
+        } else {
+            Some((SemanticPart::Text(part), part))
+        }
+    }
+}
+
+impl<'a> Iterator for SemanticParts<'a> {
+    type Item = SemanticPart<'a>;
+
+    fn next(&mut self) -> Option<SemanticPart<'a>> {
+        self.next_with_text().map(|(part, _)| part)
+    }
+}
+
+/// Returns an iterator over the text and number segments of the string, as used by
+/// `SemanticString::new`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::text::{semantic_parts, SemanticPart};
+///
+/// let parts: Vec<_> = semantic_parts("foo12bar").collect();
+/// assert_eq!(parts, vec![SemanticPart::Text("foo"), SemanticPart::Number("12"), SemanticPart::Text("bar")]);
+/// ```
+pub fn semantic_parts(s: &str) -> SemanticParts {
+    SemanticParts {
+        raw: s,
+    }
+}
+
+/// The rules used to order two `SemanticString`s with `SemanticString::cmp_with`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct SemanticOrd {
+    /// Whether numbers that differ only by leading zeros, such as `01` and `1`, are equal. If
+    /// not, the one with more leading zeros comes first.
+    pub ignore_leading_zeros: bool,
+    /// Whether text is compared ignoring case.
+    pub case_insensitive: bool,
+}
+
+#[derive(Debug)]
 pub struct SemanticString<'a> {
     pub raw: &'a str,
-    parts: Vec<StringPart<'a>>,
+    parts: Vec<SemanticPart<'a>>,
+    // The text each part was parsed from.
+    part_texts: Vec<&'a str>,
+    signed_decimal: bool,
+}
+
+impl<'a> PartialEq for SemanticString<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
 }
 
+impl<'a> Eq for SemanticString<'a> {}
+
+/// Compares the strings part by part, then by the number of parts, so a string sorts before any
+/// longer string it is a prefix of. Strings with equal parts, such as `"file01"` and `"file1"`,
+/// are ordered by their raw text, so they are only equal if their raw text is.
 impl<'a> Ord for SemanticString<'a> {
     fn cmp(&self, other: &Self) -> Ordering {
-        match self.raw.len().cmp(&other.raw.len()) {
-            Ordering::Equal => {
-                for (a,b) in self.parts.iter().zip(other.parts.iter()) {
-                    if a.cmp(b) != Ordering::Equal {
-                        return a.cmp(b)
-                    }
-                }
-
-                Ordering::Equal
-            },
-            i @ _ => i,
-        }
+        self.cmp_parts(other)
+            .then(self.parts.len().cmp(&other.parts.len()))
+            .then_with(|| self.raw.cmp(other.raw))
     }
 }
 
@@ -59,37 +205,330 @@ impl<'a> PartialOrd for SemanticString<'a> {
 }
 
 impl<'a> SemanticString<'a> {
-    pub fn new(raw: &'a str) -> SemanticString {
-        let mut parts = vec![];
-        for (is_num, mut group) in &raw.char_indices().group_by(|&(_, c)| c.is_numeric() && c.is_ascii()) {
-            let first_index = if let Some((i, _)) = group.next() {
-                i
+    fn cmp_parts(&self, other: &Self) -> Ordering {
+        for (a,b) in self.parts.iter().zip(other.parts.iter()) {
+            let ord = a.cmp(b);
+            if ord != Ordering::Equal {
+                return ord
+            }
+        }
+
+        Ordering::Equal
+    }
+
+    /// Compares the two strings part by part using the given rules, then by the number of
+    /// parts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use rust_utils::text::{SemanticOrd, SemanticString};
+    ///
+    /// let padded = SemanticString::new("file01");
+    /// let unpadded = SemanticString::new("file1");
+    ///
+    /// let cfg = SemanticOrd { ignore_leading_zeros: true, case_insensitive: false };
+    /// assert_eq!(padded.cmp_with(&unpadded, cfg), Ordering::Equal);
+    ///
+    /// let cfg = SemanticOrd { ignore_leading_zeros: false, case_insensitive: false };
+    /// assert_eq!(padded.cmp_with(&unpadded, cfg), Ordering::Less);
+    /// ```
+    pub fn cmp_with(&self, other: &SemanticString, cfg: SemanticOrd) -> Ordering {
+        let self_parts = self.parts.iter().zip(self.part_texts.iter());
+        let other_parts = other.parts.iter().zip(other.part_texts.iter());
+
+        for ((a, a_text), (b, b_text)) in self_parts.zip(other_parts) {
+            let ord = if cfg.case_insensitive {
+                a.cmp_case_insensitive(b)
             } else {
-                continue;
+                a.cmp(b)
             };
 
-            let last_index = if let Some((i, _)) = group.last() {
-                 i
+            // Equal numbers with more leading zeros have longer text.
+            let is_number = a.as_f64().is_some() && b.as_f64().is_some();
+            let ord = if is_number && !cfg.ignore_leading_zeros {
+                ord.then(b_text.len().cmp(&a_text.len()))
             } else {
-                first_index+1
+                ord
             };
 
-            let part = &raw[first_index..last_index];
-            
-            let part = if is_num {
-                StringPart::Number(part.parse().expect(&format!("tried to parse {} as an int", part))) // If this fails, things have gone badly wrong
+            if ord != Ordering::Equal {
+                return ord
+            }
+        }
+
+        self.parts.len().cmp(&other.parts.len())
+    }
+
+    pub fn new(raw: &'a str) -> SemanticString {
+        let mut tokens = semantic_parts(raw);
+        let mut parts = vec![];
+        let mut part_texts = vec![];
+
+        while let Some((part, text)) = tokens.next_with_text() {
+            parts.push(part);
+            part_texts.push(text);
+        }
+
+        SemanticString {
+            raw: raw,
+            parts: parts,
+            part_texts: part_texts,
+            signed_decimal: false,
+        }
+    }
+
+    /// Creates a string where a `-` directly before a number, and a single decimal point within
+    /// it, are treated as part of that number, such as in `"v-2"` or `"1.5rc"`.
+    ///
+    /// Signed and decimal numbers are compared by their exact value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_utils::text::SemanticString;
+    ///
+    /// let a = SemanticString::new_signed_decimal("item-10");
+    /// let b = SemanticString::new_signed_decimal("item-2");
+    /// let c = SemanticString::new_signed_decimal("item1.5");
+    ///
+    /// assert!(a < b);
+    /// assert!(b < c);
+    /// ```
+    pub fn new_signed_decimal(raw: &'a str) -> SemanticString {
+        let chars: Vec<_> = raw.char_indices().collect();
+        let is_digit = |i: usize| chars.get(i).map_or(false, |&(_, c)| c.is_ascii_digit());
+
+        let mut parts = vec![];
+        let mut part_texts = vec![];
+        let mut text_start = 0;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let (start, c) = chars[i];
+            if !(is_digit(i) || (c == '-' && is_digit(i + 1))) {
+                i += 1;
+                continue;
+            }
+
+            if text_start < start {
+                parts.push(SemanticPart::Text(&raw[text_start..start]));
+                part_texts.push(&raw[text_start..start]);
+            }
+
+            i += 1;
+            while is_digit(i) {
+                i += 1;
+            }
+
+            if chars.get(i).map_or(false, |&(_, c)| c == '.') && is_digit(i + 1) {
+                i += 1;
+                while is_digit(i) {
+                    i += 1;
+                }
+            }
+
+            let end = chars.get(i).map_or(raw.len(), |&(end, _)| end);
+            let text = &raw[start..end];
+
+            let part = if c == '-' || text.contains('.') {
+                SemanticPart::Float(text)
             } else {
-                StringPart::Text(part)
+                SemanticPart::Number(text)
             };
 
             parts.push(part);
+            part_texts.push(text);
+            text_start = end;
+        }
+
+        if text_start < raw.len() {
+            parts.push(SemanticPart::Text(&raw[text_start..]));
+            part_texts.push(&raw[text_start..]);
         }
 
         SemanticString {
             raw: raw,
             parts: parts,
+            part_texts: part_texts,
+            signed_decimal: true,
         }
     }
+
+    /// Returns a score between 0 and 1 indicating how similar the two strings are, where 1 means
+    /// the strings have identical parts.
+    ///
+    /// The parts of each string are paired up by position, and each pair is scored as follows:
+    ///
+    /// * Two text parts score 1 if they are equal, otherwise 0.
+    /// * Two numbers, `a` and `b`, score `1 / (1 + |a - b|)`, so closer numbers score higher.
+    /// * A text part paired with a number scores 0.
+    ///
+    /// The final score is the sum of the pair scores divided by the number of parts in the longer
+    /// string, so any unpaired parts count as 0. Two empty strings have a score of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_utils::text::SemanticString;
+    ///
+    /// let file2 = SemanticString::new("file2");
+    /// let file3 = SemanticString::new("file3");
+    /// let file99 = SemanticString::new("file99");
+    ///
+    /// assert!(file2.similarity(&file3) > file2.similarity(&file99));
+    /// ```
+    pub fn similarity(&self, other: &SemanticString) -> f64 {
+        use self::SemanticPart::*;
+
+        let part_count = self.parts.len().max(other.parts.len());
+        if part_count == 0 {
+            return 1.0;
+        }
+
+        let total: f64 = self.parts.iter().zip(other.parts.iter())
+            .map(|pair| match pair {
+                (a, b) if a == b => 1.0,
+                (&Text(_), _) | (_, &Text(_)) => 0.0,
+                // Numbers too long for an `f64` parse as infinite, so are treated as far apart.
+                (a, b) => match (a.as_f64(), b.as_f64()) {
+                    (Some(a), Some(b)) if (a - b).is_finite() => 1.0 / (1.0 + (a - b).abs()),
+                    _ => 0.0,
+                },
+            })
+            .sum();
+
+        total / part_count as f64
+    }
+}
+
+/// Searches a slice sorted by `SemanticString` order for `needle`, as with `binary_search`.
+///
+/// Returns the index of a matching element if there is one, or otherwise the index at which
+/// `needle` could be inserted to keep the slice sorted. Only the elements probed by the search
+/// are parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::text::semantic_search;
+///
+/// let files = ["file1", "file2", "file10"];
+/// assert_eq!(semantic_search(&files, "file10"), Ok(2));
+/// assert_eq!(semantic_search(&files, "file3"), Err(2));
+/// ```
+pub fn semantic_search(haystack: &[&str], needle: &str) -> Result<usize, usize> {
+    let needle = SemanticString::new(needle);
+    haystack.binary_search_by(|probe| SemanticString::new(probe).cmp(&needle))
+}
+
+/// Wraps a `SemanticString` so that its text parts are compared ignoring case, such as for
+/// sorting filenames. The raw string is kept as-is.
+///
+/// Strings that differ only in case are equal. Otherwise, strings with equal parts, such as
+/// `"FILE01"` and `"file1"`, are ordered by their raw text folded to lowercase.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::text::CaseInsensitive;
+///
+/// let upper = CaseInsensitive::new("FILE1");
+/// let lower = CaseInsensitive::new("file2");
+///
+/// assert!(upper < lower);
+/// assert_eq!(upper, CaseInsensitive::new("File1"));
+/// assert_eq!(upper.raw, "FILE1");
+/// ```
+#[derive(Debug)]
+pub struct CaseInsensitive<'a>(pub SemanticString<'a>);
+
+impl<'a> CaseInsensitive<'a> {
+    pub fn new(raw: &'a str) -> CaseInsensitive<'a> {
+        CaseInsensitive(SemanticString::new(raw))
+    }
+}
+
+impl<'a> PartialEq for CaseInsensitive<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for CaseInsensitive<'a> {}
+
+impl<'a> Ord for CaseInsensitive<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let cfg = SemanticOrd { ignore_leading_zeros: true, case_insensitive: true };
+
+        self.0.cmp_with(&other.0, cfg)
+            .then_with(|| {
+                let a = self.raw.chars().flat_map(char::to_lowercase);
+                let b = other.raw.chars().flat_map(char::to_lowercase);
+                a.cmp(b)
+            })
+    }
+}
+
+impl<'a> PartialOrd for CaseInsensitive<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> From<&'a str> for CaseInsensitive<'a> {
+    fn from(raw: &'a str) -> CaseInsensitive<'a> {
+        CaseInsensitive::new(raw)
+    }
+}
+
+impl<'a> Deref for CaseInsensitive<'a> {
+    type Target = SemanticString<'a>;
+    fn deref(&self) -> &SemanticString<'a> {
+        &self.0
+    }
+}
+
+/// Compares the raw string, so a string is only equal to a `str` with the same text.
+impl<'a> PartialEq<str> for SemanticString<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.raw == other
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for SemanticString<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.raw == *other
+    }
+}
+
+/// Orders semantically, parsing the other string the same way as this one. Strings that order
+/// equally are then ordered by their raw text, so the result is only `Equal` when the strings
+/// are also equal under `PartialEq<str>`.
+impl<'a> PartialOrd<str> for SemanticString<'a> {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        let other = if self.signed_decimal {
+            SemanticString::new_signed_decimal(other)
+        } else {
+            SemanticString::new(other)
+        };
+
+        Some(self.cmp(&other).then_with(|| self.raw.cmp(other.raw)))
+    }
+}
+
+impl<'a, 'b> PartialOrd<&'b str> for SemanticString<'a> {
+    fn partial_cmp(&self, other: &&'b str) -> Option<Ordering> {
+        self.partial_cmp(*other)
+    }
+}
+
+/// An empty string, which orders before every non-empty string.
+impl<'a> Default for SemanticString<'a> {
+    fn default() -> SemanticString<'a> {
+        SemanticString::new("")
+    }
 }
 
 impl<'a> From<&'a str> for SemanticString<'a> {
@@ -105,6 +544,110 @@ impl<'a> Deref for SemanticString<'a> {
     }
 }
 
+/// Serializes as the raw string.
+#[cfg(feature = "serde")]
+impl<'a> Serialize for SemanticString<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.raw)
+    }
+}
+
+/// An owned `SemanticString`, for when the text can't be borrowed, such as when deserializing
+/// a string that contains escapes.
+///
+/// The string is parsed as with `SemanticString::new` whenever it is compared.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_utils::text::SemanticStringBuf;
+///
+/// let mut strings = vec![SemanticStringBuf::from("file10"), SemanticStringBuf::from("file2")];
+/// strings.sort();
+///
+/// assert_eq!(strings[0].raw, "file2");
+/// assert_eq!(strings[1].as_semantic().raw, "file10");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SemanticStringBuf {
+    pub raw: String,
+}
+
+impl SemanticStringBuf {
+    pub fn new(raw: String) -> SemanticStringBuf {
+        SemanticStringBuf {
+            raw: raw,
+        }
+    }
+
+    /// Borrows the string as a `SemanticString`.
+    pub fn as_semantic(&self) -> SemanticString {
+        SemanticString::new(&self.raw)
+    }
+}
+
+impl PartialEq for SemanticStringBuf {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for SemanticStringBuf {}
+
+/// Orders the same as the equivalent `SemanticString`s.
+impl Ord for SemanticStringBuf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_semantic().cmp(&other.as_semantic())
+    }
+}
+
+impl PartialOrd for SemanticStringBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<String> for SemanticStringBuf {
+    fn from(raw: String) -> SemanticStringBuf {
+        SemanticStringBuf::new(raw)
+    }
+}
+
+impl<'a> From<&'a str> for SemanticStringBuf {
+    fn from(raw: &'a str) -> SemanticStringBuf {
+        SemanticStringBuf::new(raw.to_owned())
+    }
+}
+
+impl<'a> From<SemanticString<'a>> for SemanticStringBuf {
+    fn from(s: SemanticString<'a>) -> SemanticStringBuf {
+        SemanticStringBuf::new(s.raw.to_owned())
+    }
+}
+
+impl Deref for SemanticStringBuf {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.raw
+    }
+}
+
+/// Serializes as the raw string.
+#[cfg(feature = "serde")]
+impl Serialize for SemanticStringBuf {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+/// Deserializes from an owned string, so strings containing escapes can be read.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for SemanticStringBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(SemanticStringBuf::new)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,6 +696,169 @@ mod tests {
         assert_eq!(orig, vec!["2", "test"]);
     }
     
+    #[test]
+    fn case_insensitive() {
+        let strings = ["file10", "File2"];
+
+        let mut sem_strings: Vec<_> = strings.iter().map(|x| CaseInsensitive::new(x)).collect();
+        sem_strings.sort();
+        let orig: Vec<_> = sem_strings.iter().map(|x| x.raw).collect();
+
+        assert_eq!(orig, vec!["File2", "file10"]);
+
+        // Case-sensitively, "File10" would sort first as 'F' is before 'f'.
+        let strings = ["File10", "file2"];
+
+        let mut sem_strings: Vec<_> = strings.iter().map(|x| CaseInsensitive::new(x)).collect();
+        sem_strings.sort();
+        let orig: Vec<_> = sem_strings.iter().map(|x| x.raw).collect();
+
+        assert_eq!(orig, vec!["file2", "File10"]);
+    }
+
+    #[test]
+    fn case_insensitive_folds_text() {
+        let strings = ["b1", "A2", "a1"];
+
+        let mut sem_strings: Vec<_> = strings.iter().map(|x| CaseInsensitive::new(x)).collect();
+        sem_strings.sort();
+        let orig: Vec<_> = sem_strings.iter().map(|x| x.raw).collect();
+
+        assert_eq!(orig, vec!["a1", "A2", "b1"]);
+        assert_eq!(CaseInsensitive::new("ABC"), CaseInsensitive::new("abc"));
+        assert_ne!(SemanticString::new("ABC"), SemanticString::new("abc"));
+        assert_ne!(SemanticString::new("file"), SemanticString::new("File"));
+    }
+
+    #[test]
+    fn case_insensitive_total_order() {
+        let strings = ["FILE01", "file1", "File1", "file2", "FILE10", "a", "B", "b", "1", "01"];
+
+        let sem_strings: Vec<_> = strings.iter().map(|x| CaseInsensitive::new(x)).collect();
+        for a in &sem_strings {
+            for b in &sem_strings {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{} vs {}", a.raw, b.raw);
+                assert_eq!(a == b, a.raw.to_lowercase() == b.raw.to_lowercase(), "{} vs {}", a.raw, b.raw);
+
+                for c in &sem_strings {
+                    if a <= b && b <= c {
+                        assert!(a <= c, "{} <= {} <= {}", a.raw, b.raw, c.raw);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn signed_decimal_negative() {
+        let strings = ["item-2", "item-10"];
+
+        let mut sem_strings: Vec<_> = strings.iter().map(|x| SemanticString::new_signed_decimal(x)).collect();
+        sem_strings.sort();
+        let orig: Vec<_> = sem_strings.iter().map(|x| x.raw).collect();
+
+        assert_eq!(orig, vec!["item-10", "item-2"]);
+    }
+
+    #[test]
+    fn signed_decimal_mixed() {
+        let strings = ["1.5rc", "2rc", "1rc", "-1rc"];
+
+        let mut sem_strings: Vec<_> = strings.iter().map(|x| SemanticString::new_signed_decimal(x)).collect();
+        sem_strings.sort();
+        let orig: Vec<_> = sem_strings.iter().map(|x| x.raw).collect();
+
+        assert_eq!(orig, vec!["-1rc", "1rc", "1.5rc", "2rc"]);
+    }
+
+    #[test]
+    fn signed_decimal_parts() {
+        let sem_string = SemanticString::new_signed_decimal("v-2.5.1-x");
+
+        assert_eq!(sem_string.parts, vec![
+            SemanticPart::Text("v"),
+            SemanticPart::Float("-2.5"),
+            SemanticPart::Text("."),
+            SemanticPart::Number("1"),
+            SemanticPart::Text("-x"),
+        ]);
+    }
+
+    #[test]
+    fn parts() {
+        let parts: Vec<_> = semantic_parts("foo12bar").collect();
+
+        assert_eq!(parts, vec![SemanticPart::Text("foo"), SemanticPart::Number("12"), SemanticPart::Text("bar")]);
+    }
+
+    #[test]
+    fn parts_leading_number() {
+        let parts: Vec<_> = semantic_parts("12foo3").collect();
+
+        assert_eq!(parts, vec![SemanticPart::Number("12"), SemanticPart::Text("foo"), SemanticPart::Number("3")]);
+        assert_eq!(semantic_parts("").count(), 0);
+    }
+
+    #[test]
+    fn eq_str() {
+        let sem_string = SemanticString::new("file10");
+
+        assert!(sem_string == "file10");
+        assert!(sem_string != "file010");
+    }
+
+    #[test]
+    fn ord_str() {
+        let sem_string = SemanticString::new("file10");
+
+        assert!(sem_string > "file2");
+        assert!(sem_string < "file11");
+        assert!(SemanticString::new_signed_decimal("item-2") > "item-10");
+        assert_eq!(SemanticString::new("File10").partial_cmp("file10"), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn ord_str_consistent_with_eq() {
+        let strings = ["file1", "file01", "File1", "FILE1", "file10", "-1", "x"];
+        let sem_strings: Vec<_> = strings.iter().map(|x| SemanticString::new(x))
+            .chain(strings.iter().map(|x| SemanticString::new_signed_decimal(x)))
+            .collect();
+
+        for a in &sem_strings {
+            for b in &strings {
+                assert_eq!(a.partial_cmp(b) == Some(Ordering::Equal), a == b, "{} vs {}", a.raw, b);
+            }
+        }
+    }
+
+    #[test]
+    fn cmp_with_leading_zeros() {
+        let padded = SemanticString::new("file01");
+        let unpadded = SemanticString::new("file1");
+        let two = SemanticString::new("file2");
+
+        let ignore = SemanticOrd { ignore_leading_zeros: true, case_insensitive: false };
+        assert_eq!(padded.cmp_with(&unpadded, ignore), Ordering::Equal);
+        assert_eq!(padded.cmp_with(&two, ignore), Ordering::Less);
+
+        let distinct = SemanticOrd { ignore_leading_zeros: false, case_insensitive: false };
+        assert_eq!(padded.cmp_with(&unpadded, distinct), Ordering::Less);
+        assert_eq!(unpadded.cmp_with(&padded, distinct), Ordering::Greater);
+        assert_eq!(padded.cmp_with(&two, distinct), Ordering::Less);
+    }
+
+    #[test]
+    fn cmp_with_case() {
+        let upper = SemanticString::new("FILE1");
+        let lower = SemanticString::new("file1");
+
+        let sensitive = SemanticOrd::default();
+        assert_eq!(upper.cmp_with(&lower, sensitive), Ordering::Less);
+
+        let insensitive = SemanticOrd { ignore_leading_zeros: false, case_insensitive: true };
+        assert_eq!(upper.cmp_with(&lower, insensitive), Ordering::Equal);
+    }
+
     #[test]
     fn empty() {
         let string = "";
@@ -160,4 +866,193 @@ mod tests {
 
         assert_eq!(string, sem_string.raw);
     }
+
+    #[test]
+    fn default() {
+        let empty = SemanticString::default();
+
+        assert_eq!(empty.raw, "");
+        assert!(empty.parts.is_empty());
+        assert!(empty < SemanticString::new("a"));
+        assert!(empty < SemanticString::new("0"));
+        assert!(empty < SemanticString::new_signed_decimal("-1"));
+    }
+
+    #[test]
+    fn multibyte_text() {
+        let sem_string = SemanticString::new("é1ü");
+
+        assert_eq!(sem_string.parts, vec![SemanticPart::Text("é"), SemanticPart::Number("1"), SemanticPart::Text("ü")]);
+    }
+
+    #[test]
+    fn similarity_numeric_proximity() {
+        let file2 = SemanticString::new("file2");
+        let file3 = SemanticString::new("file3");
+        let file99 = SemanticString::new("file99");
+
+        assert_eq!(file2.similarity(&file3), 0.75);
+        assert!(file2.similarity(&file3) > file2.similarity(&file99));
+    }
+
+    #[test]
+    fn similarity_long_numbers() {
+        let digits: String = ::std::iter::repeat('9').take(400).collect();
+        let a = format!("file{}", digits);
+        let b = format!("file{}1", digits);
+
+        assert_eq!(SemanticString::new(&a).similarity(&SemanticString::new(&a)), 1.0);
+        assert_eq!(SemanticString::new(&a).similarity(&SemanticString::new(&b)), 0.5);
+    }
+
+    #[test]
+    fn similarity_bounds() {
+        let a = SemanticString::new("abc12");
+        let b = SemanticString::new("34def");
+
+        assert_eq!(a.similarity(&a), 1.0);
+        assert_eq!(a.similarity(&b), 0.0);
+        assert_eq!(SemanticString::new("").similarity(&SemanticString::new("")), 1.0);
+        assert_eq!(SemanticString::new("a").similarity(&SemanticString::new("a1")), 0.5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use serde_json;
+
+        let strings = vec![SemanticString::new("foo2bar"), SemanticString::new("foo11bar")];
+
+        let json = serde_json::to_string(&strings).unwrap();
+        assert_eq!(json, r#"["foo2bar","foo11bar"]"#);
+
+        let mut parsed: Vec<SemanticStringBuf> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0].raw, "foo2bar");
+        assert_eq!(parsed[1].raw, "foo11bar");
+
+        parsed.sort();
+        let orig: Vec<_> = parsed.iter().map(|x| &*x.raw).collect();
+        assert_eq!(orig, vec!["foo2bar", "foo11bar"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_escaped() {
+        use serde_json;
+
+        let json = r#"["file\"10","file\"2"]"#;
+
+        let mut parsed: Vec<SemanticStringBuf> = serde_json::from_str(json).unwrap();
+        parsed.sort();
+        assert_eq!(parsed[0].raw, "file\"2");
+        assert_eq!(parsed[1].raw, "file\"10");
+
+        let parsed: Vec<SemanticStringBuf> = serde_json::from_reader(json.as_bytes()).unwrap();
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn long_numbers() {
+        let a = "item12345678901234567890123456789012345678901234567890";
+        let b = "item12345678901234567890123456789012345678901234567891";
+
+        assert!(SemanticString::new(a) < SemanticString::new(b));
+        assert!(SemanticString::new(b) > SemanticString::new(a));
+        assert_eq!(SemanticString::new(a), SemanticString::new(a));
+    }
+
+    #[test]
+    fn number_parts_compare_by_value() {
+        assert!(SemanticPart::Number("9") < SemanticPart::Number("10"));
+        assert!(SemanticPart::Number("0099") < SemanticPart::Number("100"));
+        assert_eq!(SemanticPart::Number("007"), SemanticPart::Number("7"));
+        assert_eq!(SemanticPart::Number("000"), SemanticPart::Number("0"));
+    }
+
+    fn check_total_order<'a>(strings: &[&'a str], parse: fn(&'a str) -> SemanticString<'a>) {
+        let sem_strings: Vec<_> = strings.iter().map(|&x| parse(x)).collect();
+        for a in &sem_strings {
+            for b in &sem_strings {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{} vs {}", a.raw, b.raw);
+                assert_eq!(a == b, a.raw == b.raw, "{} vs {}", a.raw, b.raw);
+
+                for c in &sem_strings {
+                    if a <= b && b <= c {
+                        assert!(a <= c, "{} <= {} <= {}", a.raw, b.raw, c.raw);
+                    }
+                }
+            }
+        }
+
+        let sorted_raw = |order: Vec<&'a str>| {
+            let mut sem_strings: Vec<_> = order.into_iter().map(parse).collect();
+            sem_strings.sort();
+            sem_strings.iter().map(|x| x.raw).collect::<Vec<_>>()
+        };
+
+        let expected = sorted_raw(strings.to_vec());
+        for shift in 0..strings.len() {
+            let rotated: Vec<_> = strings[shift..].iter().chain(strings[..shift].iter()).cloned().collect();
+            let reversed: Vec<_> = rotated.iter().rev().cloned().collect();
+
+            assert_eq!(sorted_raw(rotated), expected);
+            assert_eq!(sorted_raw(reversed), expected);
+        }
+    }
+
+    #[test]
+    fn total_order() {
+        check_total_order(&[
+            "a", "a1", "a01", "a1b", "a2", "a10", "b", "1", "01", "10", "9a", "", "A", "a1a",
+            "file12345678901234567890", "file9", "file09", "x-1", "x0",
+        ], SemanticString::new);
+    }
+
+    #[test]
+    fn total_order_signed_decimal() {
+        check_total_order(&[
+            "x10000000000000001", "x10000000000000001.0", "x9999999999999999",
+            "x-1", "x-1.5", "x-0", "x0", "x0.0", "x-0.0", "x1.25", "x1.3", "x1.30", "x-10.01",
+            "x-10.1", "x01.5", "1.5", "-1.5", "x", "xa",
+        ], SemanticString::new_signed_decimal);
+    }
+
+    #[test]
+    fn signed_decimal_exact() {
+        let a = SemanticString::new_signed_decimal("x10000000000000001");
+        let b = SemanticString::new_signed_decimal("x10000000000000001.0");
+        let c = SemanticString::new_signed_decimal("x9999999999999999");
+
+        assert!(c < a);
+        assert!(a < b);
+        assert!(c < b);
+        assert!(SemanticString::new_signed_decimal("-10.1") < SemanticString::new_signed_decimal("-10.01"));
+        assert!(SemanticString::new_signed_decimal("1.25") < SemanticString::new_signed_decimal("1.3"));
+    }
+
+    #[test]
+    fn search() {
+        let sorted = ["1", "2", "10", "a", "a2", "a10", "b"];
+
+        for (i, s) in sorted.iter().enumerate() {
+            assert_eq!(semantic_search(&sorted, s), Ok(i));
+        }
+
+        assert_eq!(semantic_search(&sorted, "0"), Err(0));
+        assert_eq!(semantic_search(&sorted, "9"), Err(2));
+        assert_eq!(semantic_search(&sorted, "a3"), Err(5));
+        assert_eq!(semantic_search(&sorted, "c"), Err(7));
+        assert_eq!(semantic_search(&[], "a"), Err(0));
+    }
+
+    #[test]
+    fn search_matches_sort() {
+        let mut sem_strings: Vec<_> = ["x10", "x9", "x1y", "x", "10", "y"].iter().map(|x| SemanticString::new(x)).collect();
+        sem_strings.sort();
+        let sorted: Vec<_> = sem_strings.iter().map(|x| x.raw).collect();
+
+        for (i, s) in sorted.iter().enumerate() {
+            assert_eq!(semantic_search(&sorted, s), Ok(i));
+        }
+    }
 }
\ No newline at end of file
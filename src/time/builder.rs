@@ -0,0 +1,205 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use super::TimeSpanError;
+
+const NANOS_PER_MILLISECOND: u128 = 1_000_000;
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+const NANOS_PER_MINUTE: u128 = NANOS_PER_SECOND * 60;
+const NANOS_PER_HOUR: u128 = NANOS_PER_MINUTE * 60;
+const NANOS_PER_DAY: u128 = NANOS_PER_HOUR * 24;
+
+/// Builds a `Duration` by adding up fields of different units.
+///
+/// Each field adds to the total, so fields are not limited to their usual range and may be
+/// set more than once. Overflow is reported when the duration is built.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rust_utils::time::TimeSpanBuilder;
+///
+/// let span = TimeSpanBuilder::new().days(1).hours(2).minutes(3).seconds(4).millis(5).build();
+/// assert_eq!(span, Ok(Duration::new(93_784, 5_000_000)));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TimeSpanBuilder {
+    nanos: Option<u128>,
+}
+
+impl TimeSpanBuilder {
+    pub fn new() -> TimeSpanBuilder {
+        TimeSpanBuilder {
+            nanos: Some(0),
+        }
+    }
+
+    fn add(self, count: u64, unit: u128) -> TimeSpanBuilder {
+        TimeSpanBuilder {
+            nanos: self.nanos.and_then(|n| (count as u128).checked_mul(unit).and_then(|v| n.checked_add(v))),
+        }
+    }
+
+    pub fn days(self, days: u64) -> TimeSpanBuilder {
+        self.add(days, NANOS_PER_DAY)
+    }
+
+    pub fn hours(self, hours: u64) -> TimeSpanBuilder {
+        self.add(hours, NANOS_PER_HOUR)
+    }
+
+    pub fn minutes(self, minutes: u64) -> TimeSpanBuilder {
+        self.add(minutes, NANOS_PER_MINUTE)
+    }
+
+    pub fn seconds(self, seconds: u64) -> TimeSpanBuilder {
+        self.add(seconds, NANOS_PER_SECOND)
+    }
+
+    pub fn millis(self, millis: u64) -> TimeSpanBuilder {
+        self.add(millis, NANOS_PER_MILLISECOND)
+    }
+
+    /// Returns the total of all the fields, or `TimeSpanError::Overflow` if it is too large for
+    /// a `Duration`.
+    pub fn build(self) -> Result<Duration, TimeSpanError> {
+        let nanos = self.nanos.ok_or(TimeSpanError::Overflow)?;
+
+        let secs = nanos / NANOS_PER_SECOND;
+        if secs > u64::max_value() as u128 {
+            return Err(TimeSpanError::Overflow);
+        }
+
+        Ok(Duration::new(secs as u64, (nanos % NANOS_PER_SECOND) as u32))
+    }
+}
+
+impl Default for TimeSpanBuilder {
+    fn default() -> TimeSpanBuilder {
+        TimeSpanBuilder::new()
+    }
+}
+
+/// A time span split into named fields, for destructuring and rebuilding a `Duration`.
+///
+/// Converting from a `Duration` gives each field in its usual range, such as `0..24` for hours
+/// and `0..1_000_000` for the nanoseconds left over after the milliseconds. Converting back adds
+/// up all the fields, so they may be out of range, and fails with `TimeSpanError::Overflow` if
+/// the total is too large.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use std::time::Duration;
+/// use rust_utils::time::TimeSpanParts;
+///
+/// let mut parts = TimeSpanParts::from(Duration::new(93_784, 5_000_006));
+/// assert_eq!((parts.days, parts.hours, parts.minutes, parts.seconds), (1, 2, 3, 4));
+/// assert_eq!((parts.millis, parts.nanos), (5, 6));
+///
+/// parts.days = 0;
+/// assert_eq!(Duration::try_from(parts), Ok(Duration::new(7_384, 5_000_006)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct TimeSpanParts {
+    pub days: u64,
+    pub hours: u64,
+    pub minutes: u64,
+    pub seconds: u64,
+    pub millis: u64,
+    pub nanos: u64,
+}
+
+impl From<Duration> for TimeSpanParts {
+    fn from(span: Duration) -> TimeSpanParts {
+        let nanos = span.as_secs() as u128 * NANOS_PER_SECOND + span.subsec_nanos() as u128;
+
+        TimeSpanParts {
+            days: (nanos / NANOS_PER_DAY) as u64,
+            hours: (nanos % NANOS_PER_DAY / NANOS_PER_HOUR) as u64,
+            minutes: (nanos % NANOS_PER_HOUR / NANOS_PER_MINUTE) as u64,
+            seconds: (nanos % NANOS_PER_MINUTE / NANOS_PER_SECOND) as u64,
+            millis: (nanos % NANOS_PER_SECOND / NANOS_PER_MILLISECOND) as u64,
+            nanos: (nanos % NANOS_PER_MILLISECOND) as u64,
+        }
+    }
+}
+
+impl TryFrom<TimeSpanParts> for Duration {
+    type Error = TimeSpanError;
+
+    fn try_from(parts: TimeSpanParts) -> Result<Duration, TimeSpanError> {
+        TimeSpanBuilder::new()
+            .days(parts.days)
+            .hours(parts.hours)
+            .minutes(parts.minutes)
+            .seconds(parts.seconds)
+            .millis(parts.millis)
+            .add(parts.nanos, 1)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+    use std::time::Duration;
+    use std::u64;
+    use super::*;
+
+    #[test]
+    fn carry() {
+        let span = TimeSpanBuilder::new().hours(1).minutes(90).build();
+
+        assert_eq!(span, Ok(Duration::new(9000, 0)));
+    }
+
+    #[test]
+    fn accumulates() {
+        let span = TimeSpanBuilder::new().seconds(1).seconds(2).millis(1500).build();
+
+        assert_eq!(span, Ok(Duration::new(4, 500_000_000)));
+        assert_eq!(TimeSpanBuilder::new().build(), Ok(Duration::new(0, 0)));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(TimeSpanBuilder::new().days(u64::MAX).build(), Err(TimeSpanError::Overflow));
+        assert_eq!(TimeSpanBuilder::new().seconds(u64::MAX).seconds(1).build(), Err(TimeSpanError::Overflow));
+        assert!(TimeSpanBuilder::new().seconds(u64::MAX).millis(999).build().is_ok());
+    }
+
+    #[test]
+    fn parts_round_trip() {
+        let spans = [
+            Duration::new(0, 0),
+            Duration::new(0, 999_999_999),
+            Duration::new(59, 1),
+            Duration::new(93_784, 5_000_006),
+            Duration::new(u64::MAX, 999_999_999),
+        ];
+
+        for &span in &spans {
+            let parts = TimeSpanParts::from(span);
+            assert_eq!(Duration::try_from(parts), Ok(span));
+        }
+    }
+
+    #[test]
+    fn parts_fields() {
+        let parts = TimeSpanParts::from(Duration::new(2 * 86_400 + 23 * 3600 + 59 * 60 + 58, 123_456_789));
+
+        assert_eq!(parts, TimeSpanParts { days: 2, hours: 23, minutes: 59, seconds: 58, millis: 123, nanos: 456_789 });
+    }
+
+    #[test]
+    fn parts_overflow() {
+        let parts = TimeSpanParts { days: u64::MAX, ..TimeSpanParts::default() };
+        let result: Result<Duration, _> = parts.try_into();
+
+        assert_eq!(result, Err(TimeSpanError::Overflow));
+        assert_eq!(Duration::try_from(TimeSpanParts { millis: 1500, ..TimeSpanParts::default() }), Ok(Duration::new(1, 500_000_000)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,83 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{clock_string, Rounding};
+
+/// Returns the time that has passed since `start`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use rust_utils::time::elapsed_span;
+///
+/// let start = Instant::now();
+/// assert!(elapsed_span(start) >= Duration::new(0, 0));
+/// ```
+pub fn elapsed_span(start: Instant) -> Duration {
+    start.elapsed()
+}
+
+/// Formats the time that has passed since `start` as `MM:SS.mmm`, or `HH:MM:SS.mmm` once at
+/// least an hour has passed, for reporting how long some work took.
+///
+/// Partial milliseconds are dropped, as with `clock_string` using `Rounding::Down`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Instant;
+/// use rust_utils::time::format_elapsed;
+///
+/// let start = Instant::now();
+/// // Do some work...
+/// println!("Finished in {}", format_elapsed(start));
+/// ```
+pub fn format_elapsed(start: Instant) -> String {
+    clock_string(elapsed_span(start), Rounding::Down, true)
+}
+
+/// Sleeps the current thread for at least `span`, and returns how long it actually slept.
+///
+/// The thread may oversleep, so a loop running at a fixed rate can subtract the extra time from
+/// its next sleep.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rust_utils::time::sleep_span;
+///
+/// let slept = sleep_span(Duration::new(0, 1_000_000));
+/// assert!(slept >= Duration::new(0, 1_000_000));
+/// ```
+pub fn sleep_span(span: Duration) -> Duration {
+    let start = Instant::now();
+    thread::sleep(span);
+    start.elapsed()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use super::*;
+
+    #[test]
+    fn elapsed_since_past() {
+        let start = Instant::now();
+        let elapsed = elapsed_span(start);
+
+        assert!(elapsed >= Duration::new(0, 0));
+        assert!(elapsed_span(start) >= elapsed);
+    }
+
+    #[test]
+    fn sleep_at_least_requested() {
+        let requested = Duration::new(0, 20_000_000);
+        let slept = sleep_span(requested);
+
+        assert!(slept >= requested);
+        // Only catches a grossly wrong measurement, as a busy machine may oversleep a lot.
+        assert!(slept < Duration::new(10, 0));
+    }
+}
\ No newline at end of file
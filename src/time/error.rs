@@ -0,0 +1,28 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TimeSpanError {
+    /// The input text was not a valid duration.
+    Parse(String),
+    /// An entry in a list of durations was invalid. Holds the zero-based position of the entry,
+    /// and the error it caused.
+    ListEntry(usize, Box<TimeSpanError>),
+    /// The result was too large to be represented.
+    Overflow,
+    /// The result would have been negative, which time spans cannot represent.
+    Negative,
+}
+
+impl fmt::Display for TimeSpanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TimeSpanError::Parse(ref input) => write!(f, "Invalid timespan: {:?}", input),
+            TimeSpanError::ListEntry(pos, ref err) => write!(f, "Entry {}: {}", pos, err),
+            TimeSpanError::Overflow => write!(f, "Timespan overflowed"),
+            TimeSpanError::Negative => write!(f, "Timespan would be negative"),
+        }
+    }
+}
+
+impl Error for TimeSpanError {}
\ No newline at end of file
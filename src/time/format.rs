@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+const NANOS_PER_MILLISECOND: u128 = 1_000_000;
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+const SECONDS_PER_MINUTE: u128 = 60;
+const SECONDS_PER_HOUR: u128 = SECONDS_PER_MINUTE * 60;
+
+/// How `clock_string` treats time smaller than the last unit it shows.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Rounding {
+    /// Drop the extra time.
+    Down,
+    /// Round up to the next whole unit if there is any extra time.
+    Up,
+}
+
+/// Formats a time span as `MM:SS`, or `HH:MM:SS` once it is at least an hour long, optionally
+/// followed by milliseconds as `.mmm`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rust_utils::time::{clock_string, Rounding};
+///
+/// let span = Duration::new(3725, 250_500_000);
+/// assert_eq!(clock_string(span, Rounding::Down, false), "01:02:05");
+/// assert_eq!(clock_string(span, Rounding::Up, false), "01:02:06");
+/// assert_eq!(clock_string(span, Rounding::Up, true), "01:02:05.251");
+/// ```
+pub fn clock_string(span: Duration, rounding: Rounding, show_millis: bool) -> String {
+    let unit = if show_millis { NANOS_PER_MILLISECOND } else { NANOS_PER_SECOND };
+
+    let mut nanos = span.as_secs() as u128 * NANOS_PER_SECOND + span.subsec_nanos() as u128;
+    if rounding == Rounding::Up {
+        nanos += unit - 1;
+    }
+
+    let secs = nanos / NANOS_PER_SECOND;
+    let hours = secs / SECONDS_PER_HOUR;
+    let minutes = (secs % SECONDS_PER_HOUR) / SECONDS_PER_MINUTE;
+    let seconds = secs % SECONDS_PER_MINUTE;
+
+    let mut text = if hours > 0 {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    };
+
+    if show_millis {
+        text += &format!(".{:03}", nanos % NANOS_PER_SECOND / NANOS_PER_MILLISECOND);
+    }
+
+    text
+}
+
+/// Formats the time remaining on a countdown as `MM:SS`, or `HH:MM:SS` once there is at least an
+/// hour left.
+///
+/// Partial seconds are rounded up, so the countdown only shows `00:00` once no time remains.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rust_utils::time::countdown_string;
+///
+/// assert_eq!(countdown_string(Duration::new(90, 0)), "01:30");
+/// assert_eq!(countdown_string(Duration::new(3725, 0)), "01:02:05");
+/// assert_eq!(countdown_string(Duration::new(0, 0)), "00:00");
+/// ```
+pub fn countdown_string(remaining: Duration) -> String {
+    clock_string(remaining, Rounding::Up, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    #[test]
+    fn countdown_minutes() {
+        assert_eq!(countdown_string(Duration::new(90, 0)), "01:30");
+    }
+
+    #[test]
+    fn countdown_hours() {
+        assert_eq!(countdown_string(Duration::new(3600, 0)), "01:00:00");
+        assert_eq!(countdown_string(Duration::new(100_000, 0)), "27:46:40");
+    }
+
+    #[test]
+    fn countdown_zero() {
+        assert_eq!(countdown_string(Duration::new(0, 0)), "00:00");
+    }
+
+    #[test]
+    fn countdown_partial_second() {
+        assert_eq!(countdown_string(Duration::new(0, 1)), "00:01");
+        assert_eq!(countdown_string(Duration::new(59, 500_000_000)), "01:00");
+    }
+
+    #[test]
+    fn countdown_max() {
+        assert_eq!(countdown_string(Duration::new(u64::max_value(), 1)), "5124095576030431:00:16");
+    }
+
+    #[test]
+    fn clock_millis() {
+        assert_eq!(clock_string(Duration::new(0, 0), Rounding::Down, true), "00:00.000");
+        assert_eq!(clock_string(Duration::new(90, 250_999_999), Rounding::Down, true), "01:30.250");
+        assert_eq!(clock_string(Duration::new(90, 250_000_001), Rounding::Up, true), "01:30.251");
+        assert_eq!(clock_string(Duration::new(3725, 5_000_000), Rounding::Down, true), "01:02:05.005");
+    }
+
+    #[test]
+    fn clock_rounding_carries() {
+        assert_eq!(clock_string(Duration::new(3599, 999_999_001), Rounding::Up, true), "01:00:00.000");
+        assert_eq!(clock_string(Duration::new(3599, 999_999_001), Rounding::Down, false), "59:59");
+    }
+}
\ No newline at end of file
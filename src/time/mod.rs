@@ -1,2 +1,23 @@
 pub mod timespan;
-pub use self::timespan::*;
\ No newline at end of file
+pub use self::timespan::*;
+
+pub mod error;
+pub use self::error::*;
+
+pub mod parse;
+pub use self::parse::*;
+
+pub mod stats;
+pub use self::stats::*;
+
+pub mod format;
+pub use self::format::*;
+
+pub mod schedule;
+pub use self::schedule::*;
+
+pub mod builder;
+pub use self::builder::*;
+
+pub mod elapsed;
+pub use self::elapsed::*;
\ No newline at end of file
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use super::{TimeSpan, TimeSpanError};
+
+/// Parses a compact duration such as `"1.5s"`, `"500ms"`, or `"2m"`.
+///
+/// The number may be whole or fractional, and must be followed by one of the units `ms`, `s`,
+/// `m`, `h`, or `d`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rust_utils::time::parse_duration;
+///
+/// assert_eq!(parse_duration("500ms"), Ok(Duration::new(0, 500_000_000)));
+/// assert_eq!(parse_duration("2m"), Ok(Duration::new(120, 0)));
+/// ```
+pub fn parse_duration(s: &str) -> Result<Duration, TimeSpanError> {
+    let text = s.trim();
+    let err = || TimeSpanError::Parse(s.to_owned());
+
+    let unit_start = text.find(|c: char| c.is_alphabetic()).ok_or_else(err)?;
+    let (number, unit) = text.split_at(unit_start);
+    let number: f64 = number.parse().map_err(|_| err())?;
+
+    let span = match unit {
+        "ms" => Duration::from_total_seconds(number / 1000.0),
+        "s" => Duration::from_total_seconds(number),
+        "m" => Duration::from_total_minutes(number),
+        "h" => Duration::from_total_hours(number),
+        "d" => Duration::from_total_days(number),
+        _ => return Err(err()),
+    };
+
+    span.map_err(|_| err())
+}
+
+/// Parses a comma separated list of compact durations, such as `"1s,500ms,2m"`.
+///
+/// Each entry is parsed with [`parse_duration`](fn.parse_duration.html). The first invalid entry
+/// is reported along with its position in the list.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rust_utils::time::parse_duration_list;
+///
+/// let spans = parse_duration_list("1s,500ms,2m").unwrap();
+/// assert_eq!(spans, vec![Duration::new(1, 0), Duration::new(0, 500_000_000), Duration::new(120, 0)]);
+/// ```
+pub fn parse_duration_list(s: &str) -> Result<Vec<Duration>, TimeSpanError> {
+    s.split(',')
+        .enumerate()
+        .map(|(i, entry)| parse_duration(entry).map_err(|e| TimeSpanError::ListEntry(i, Box::new(e))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    #[test]
+    fn units() {
+        assert_eq!(parse_duration("250ms"), Ok(Duration::new(0, 250_000_000)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::new(1, 500_000_000)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::new(120, 0)));
+        assert_eq!(parse_duration("3h"), Ok(Duration::new(10800, 0)));
+        assert_eq!(parse_duration("1d"), Ok(Duration::new(86400, 0)));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("12").is_err());
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("5y").is_err());
+        assert!(parse_duration("-5s").is_err());
+    }
+
+    #[test]
+    fn list() {
+        let spans = parse_duration_list("1s, 500ms ,2m").unwrap();
+
+        assert_eq!(spans, vec![Duration::new(1, 0), Duration::new(0, 500_000_000), Duration::new(120, 0)]);
+    }
+
+    #[test]
+    fn list_malformed_entry() {
+        let err = parse_duration_list("1s,5x,2m,bad").unwrap_err();
+
+        assert_eq!(err, TimeSpanError::ListEntry(1, Box::new(TimeSpanError::Parse("5x".to_owned()))));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// A sequence of named phases, each lasting for a given time span, such as the stages of an
+/// interval timer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Phases {
+    phases: Vec<(String, Duration)>,
+}
+
+impl Phases {
+    /// Creates a schedule from the given phases, which run in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::Phases;
+    ///
+    /// let phases = Phases::new(vec![("warmup", Duration::new(60, 0)), ("run", Duration::new(300, 0))]);
+    /// assert_eq!(phases.phase_at(Duration::new(90, 0)), Some("run"));
+    /// ```
+    pub fn new<S: Into<String>>(phases: Vec<(S, Duration)>) -> Phases {
+        Phases {
+            phases: phases.into_iter().map(|(name, span)| (name.into(), span)).collect(),
+        }
+    }
+
+    /// Returns the name of the phase running at `elapsed` into the schedule, or `None` if the
+    /// schedule has finished.
+    ///
+    /// Each phase starts at the moment the previous one ends, so a boundary belongs to the
+    /// phase starting there. If the phases add up to more than the longest `Duration`, the phase
+    /// that crosses it runs until the end of the schedule.
+    pub fn phase_at(&self, elapsed: Duration) -> Option<&str> {
+        let mut end = Duration::new(0, 0);
+
+        for &(ref name, span) in &self.phases {
+            end = match end.checked_add(span) {
+                Some(end) => end,
+                None => return Some(name),
+            };
+
+            if elapsed < end {
+                return Some(name);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the combined length of all the phases, or the longest `Duration` if they add up to
+    /// more than that.
+    pub fn total(&self) -> Duration {
+        let max = Duration::new(u64::max_value(), NANOS_PER_SECOND as u32 - 1);
+        self.phases.iter().fold(Duration::new(0, 0), |acc, &(_, span)| acc.checked_add(span).unwrap_or(max))
+    }
+}
+
+fn to_nanos(d: Duration) -> u128 {
+    d.as_secs() as u128 * NANOS_PER_SECOND + d.subsec_nanos() as u128
+}
+
+/// Returns the first time at or after `now` that falls on a recurring schedule, which repeats
+/// every `period` starting from `phase`.
+///
+/// The phase is taken modulo the period, so the result is the smallest offset no earlier than
+/// `now` that is congruent to `phase` modulo `period`.
+///
+/// # Panics
+///
+/// Panics if `period` is zero, or if the next occurrence cannot be represented by a `Duration`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rust_utils::time::next_occurrence;
+///
+/// let next = next_occurrence(Duration::new(100, 0), Duration::new(60, 0), Duration::new(15, 0));
+/// assert_eq!(next, Duration::new(135, 0));
+/// ```
+pub fn next_occurrence(now: Duration, period: Duration, phase: Duration) -> Duration {
+    let period = to_nanos(period);
+    assert!(period > 0, "next_occurrence called with a zero period");
+
+    let now = to_nanos(now);
+    let phase = to_nanos(phase) % period;
+
+    let next = if now <= phase {
+        phase
+    } else {
+        let periods = (now - phase + period - 1) / period;
+        phase + periods * period
+    };
+
+    let secs = next / NANOS_PER_SECOND;
+    assert!(secs <= u64::max_value() as u128, "next occurrence overflows Duration");
+    Duration::new(secs as u64, (next % NANOS_PER_SECOND) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    fn workout() -> Phases {
+        Phases::new(vec![
+            ("warmup", Duration::new(60, 0)),
+            ("run", Duration::new(300, 0)),
+            ("cooldown", Duration::new(120, 0)),
+        ])
+    }
+
+    #[test]
+    fn phase_at() {
+        let phases = workout();
+
+        assert_eq!(phases.phase_at(Duration::new(0, 0)), Some("warmup"));
+        assert_eq!(phases.phase_at(Duration::new(59, 999_999_999)), Some("warmup"));
+        assert_eq!(phases.phase_at(Duration::new(200, 0)), Some("run"));
+        assert_eq!(phases.phase_at(Duration::new(400, 0)), Some("cooldown"));
+    }
+
+    #[test]
+    fn phase_at_boundaries() {
+        let phases = workout();
+
+        assert_eq!(phases.phase_at(Duration::new(60, 0)), Some("run"));
+        assert_eq!(phases.phase_at(Duration::new(360, 0)), Some("cooldown"));
+    }
+
+    #[test]
+    fn phase_at_past_end() {
+        let phases = workout();
+
+        assert_eq!(phases.phase_at(Duration::new(480, 0)), None);
+        assert_eq!(phases.phase_at(Duration::new(1000, 0)), None);
+    }
+
+    #[test]
+    fn total() {
+        assert_eq!(workout().total(), Duration::new(480, 0));
+        assert_eq!(Phases::new(Vec::<(String, Duration)>::new()).total(), Duration::new(0, 0));
+    }
+
+    #[test]
+    fn phases_overflow() {
+        let max = Duration::new(u64::max_value(), 999_999_999);
+        let phases = Phases::new(vec![("a", Duration::new(10, 0)), ("b", max), ("c", max)]);
+
+        assert_eq!(phases.phase_at(Duration::new(5, 0)), Some("a"));
+        assert_eq!(phases.phase_at(Duration::new(20, 0)), Some("b"));
+        assert_eq!(phases.phase_at(max), Some("b"));
+        assert_eq!(phases.total(), max);
+    }
+
+    #[test]
+    fn next_occurrence_before_phase() {
+        let next = next_occurrence(Duration::new(5, 0), Duration::new(60, 0), Duration::new(15, 0));
+
+        assert_eq!(next, Duration::new(15, 0));
+    }
+
+    #[test]
+    fn next_occurrence_at_phase() {
+        let period = Duration::new(60, 0);
+        let phase = Duration::new(15, 0);
+
+        assert_eq!(next_occurrence(Duration::new(15, 0), period, phase), Duration::new(15, 0));
+        assert_eq!(next_occurrence(Duration::new(75, 0), period, phase), Duration::new(75, 0));
+    }
+
+    #[test]
+    fn next_occurrence_after_phase() {
+        let period = Duration::new(60, 0);
+        let phase = Duration::new(15, 0);
+
+        assert_eq!(next_occurrence(Duration::new(16, 0), period, phase), Duration::new(75, 0));
+        assert_eq!(next_occurrence(Duration::new(15, 1), period, phase), Duration::new(75, 0));
+        assert_eq!(next_occurrence(Duration::new(100, 0), period, phase), Duration::new(135, 0));
+    }
+
+    #[test]
+    fn next_occurrence_phase_wraps() {
+        let next = next_occurrence(Duration::new(0, 0), Duration::new(60, 0), Duration::new(75, 0));
+
+        assert_eq!(next, Duration::new(15, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn next_occurrence_zero_period() {
+        next_occurrence(Duration::new(5, 0), Duration::new(0, 0), Duration::new(0, 0));
+    }
+}
\ No newline at end of file
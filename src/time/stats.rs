@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Returns the mean of the given durations, weighted by the count paired with each one.
+///
+/// The sum is accumulated in nanoseconds using 128-bit integers, so it can grow well beyond what
+/// a `u64` could hold. Returns `None` if the total weight is zero, or if the weighted sum
+/// overflows even that, which takes durations and weights near their maximums.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rust_utils::time::weighted_average_durations;
+///
+/// let samples = [(Duration::new(1, 0), 3), (Duration::new(5, 0), 1)];
+/// assert_eq!(weighted_average_durations(&samples), Some(Duration::new(2, 0)));
+/// ```
+pub fn weighted_average_durations(samples: &[(Duration, u64)]) -> Option<Duration> {
+    let total_weight: u128 = samples.iter().map(|&(_, w)| w as u128).sum();
+    if total_weight == 0 {
+        return None;
+    }
+
+    let mut total_nanos: u128 = 0;
+    for &(d, w) in samples {
+        let nanos = d.as_secs() as u128 * NANOS_PER_SECOND + d.subsec_nanos() as u128;
+        total_nanos = total_nanos.checked_add(nanos.checked_mul(w as u128)?)?;
+    }
+
+    let mean = total_nanos / total_weight;
+    Some(Duration::new((mean / NANOS_PER_SECOND) as u64, (mean % NANOS_PER_SECOND) as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    #[test]
+    fn weighted_average() {
+        let samples = [(Duration::new(0, 100_000_000), 9), (Duration::new(1, 0), 1)];
+
+        assert_eq!(weighted_average_durations(&samples), Some(Duration::new(0, 190_000_000)));
+    }
+
+    #[test]
+    fn weighted_average_large() {
+        // The weighted sum is far beyond what fits in a u64 of nanoseconds.
+        let samples = [(Duration::new(1_000_000_000, 0), 1_000_000), (Duration::new(0, 0), 1_000_000)];
+
+        assert_eq!(weighted_average_durations(&samples), Some(Duration::new(500_000_000, 0)));
+    }
+
+    #[test]
+    fn weighted_average_zero_weight() {
+        let samples = [(Duration::new(1, 0), 0)];
+
+        assert_eq!(weighted_average_durations(&samples), None);
+        assert_eq!(weighted_average_durations(&[]), None);
+    }
+
+    #[test]
+    fn weighted_average_extremes() {
+        let max = Duration::new(u64::max_value(), 999_999_999);
+
+        assert_eq!(weighted_average_durations(&[(max, 1), (max, 1)]), Some(max));
+        assert_eq!(weighted_average_durations(&[(max, u64::max_value())]), None);
+        assert_eq!(weighted_average_durations(&[(max, 1 << 40), (max, 1 << 40)]), None);
+    }
+}
\ No newline at end of file
@@ -1,11 +1,20 @@
 use std::time::Duration;
 
+use super::TimeSpanError;
+
 const NANOS_PER_MILLISECOND_F: f64 = 1_000_000.0;
 const NANOS_PER_SECOND_F: f64 = 1_000_000_000.0;
+const NANOS_PER_MICROSECOND_F: f64 = 1_000.0;
 const NANOS_PER_MILLISECOND: u32 = 1_000_000;
+const NANOS_PER_MICROSECOND: u32 = 1_000;
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const MICROS_PER_SECOND: u64 = 1_000_000;
 const SECONDS_PER_MINUTE: u64 = 60;
 const SECONDS_PER_HOUR: u64 = SECONDS_PER_MINUTE * 60;
 const SECONDS_PER_DAY: u64 = SECONDS_PER_HOUR * 24;
+const DAYS_PER_WEEK: u64 = 7;
+const DEGREES_PER_HOUR: f64 = 360.0 / 12.0;
+const DEGREES_PER_MINUTE: f64 = 360.0 / 60.0;
 
 /// Trait is based on .Net's [`TimeSpan`](https://docs.microsoft.com/en-us/dotnet/api/system.timespan?view=netframework-4.7) type.
 pub trait TimeSpan<T> {
@@ -69,6 +78,44 @@ pub trait TimeSpan<T> {
     /// assert_eq!(span.partial_milliseconds(), 236);
     /// ```
     fn partial_milliseconds(&self) -> u16;
+    /// Returns the microseconds part of the time span.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::new(2, 345_678_901);
+    /// assert_eq!(span.partial_microseconds(), 678);
+    /// ```
+    fn partial_microseconds(&self) -> u32;
+    /// Returns the nanoseconds within the current millisecond of the time span. This includes
+    /// the whole microseconds, so will be in the range `0..1_000_000`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::new(2, 345_678_901);
+    /// assert_eq!(span.partial_nanoseconds(), 678_901);
+    /// ```
+    fn partial_nanoseconds(&self) -> u32;
+    /// Returns all of the partial components of the time span at once, as a tuple of
+    /// `(days, hours, minutes, seconds, milliseconds)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::from_total_days(5.31545413).unwrap();
+    /// assert_eq!(span.components(), (5, 7, 34, 15, 236));
+    /// ```
+    fn components(&self) -> (u64, u8, u8, u8, u16);
 
     /// Returns the total number of days, whole and fractional, represented by the time span.
     ///
@@ -138,7 +185,42 @@ pub trait TimeSpan<T> {
     /// assert_eq!(span, 459255237.0);
     /// ```
     fn total_milliseconds(&self) -> f64;
+    /// Returns the total number of microseconds, whole and fractional, represented by the time span.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::new(2, 345_678_900);
+    /// assert_eq!(span.total_microseconds(), 2345678.9);
+    /// ```
+    fn total_microseconds(&self) -> f64;
+    /// Returns the total number of nanoseconds represented by the time span.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::new(2, 345_678_901);
+    /// assert_eq!(span.total_nanoseconds(), 2345678901.0);
+    /// ```
+    fn total_nanoseconds(&self) -> f64;
 
+    /// Returns a timespan representing the given number of weeks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let fortnight = Duration::from_total_weeks(2.0);
+    /// ```
+    fn from_total_weeks(weeks: f64) -> Result<T, String>;
     /// Returns a timespan representing the given number of days.
     ///
     /// # Examples
@@ -194,6 +276,29 @@ pub trait TimeSpan<T> {
     /// let milliseconds = Duration::from_total_milliseconds(516.0);
     /// ```
     fn from_total_milliseconds(milliseconds: f64) -> Result<T, String>;
+    /// Returns a timespan representing the given number of microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let microseconds = Duration::from_total_microseconds(1500.5);
+    /// ```
+    fn from_total_microseconds(microseconds: f64) -> Result<T, String>;
+    /// Returns a timespan representing the given number of nanoseconds, rounded to the nearest
+    /// nanosecond.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let nanoseconds = Duration::from_total_nanoseconds(1500.0);
+    /// ```
+    fn from_total_nanoseconds(nanoseconds: f64) -> Result<T, String>;
 
     /// Returns a timespan representing the given number of days.
     ///
@@ -250,6 +355,256 @@ pub trait TimeSpan<T> {
     /// let milliseconds = Duration::from_milliseconds(516);
     /// ```
     fn from_milliseconds(milliseconds: u64) -> T;
+    /// Returns a timespan representing the given number of microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let microseconds = Duration::from_microseconds(1500);
+    /// ```
+    fn from_microseconds(microseconds: u64) -> T;
+    /// Returns a timespan representing the given number of nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let nanoseconds = Duration::from_nanoseconds(1500);
+    /// ```
+    fn from_nanoseconds(nanoseconds: u64) -> T;
+    /// Returns a timespan representing the given hours, minutes, and seconds combined.
+    ///
+    /// Minutes and seconds are not limited to 59, and carry over into the larger units, so
+    /// `from_hms(0, 90, 0)` is an hour and a half.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of seconds overflows a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::from_hms(1, 30, 15);
+    /// assert_eq!(span.components(), (0, 1, 30, 15, 0));
+    /// ```
+    fn from_hms(hours: u64, minutes: u64, seconds: u64) -> T;
+    /// Returns a timespan representing the given days, hours, minutes, and seconds combined.
+    ///
+    /// As with `from_hms`, hours, minutes, and seconds carry over into the larger units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of seconds overflows a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::from_dhms(2, 25, 0, 0);
+    /// assert_eq!(span.components(), (3, 1, 0, 0, 0));
+    /// ```
+    fn from_dhms(days: u64, hours: u64, minutes: u64, seconds: u64) -> T;
+
+    /// Returns the angles, in degrees clockwise from 12 o'clock, of the hour, minute, and second
+    /// hands of an analog clock showing the time of day component of the time span.
+    ///
+    /// The hands move continuously, so the hour hand sits part way between hours as the minutes
+    /// progress.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::from_hours(3);
+    /// assert_eq!(span.as_clock_angles(), (90.0, 0.0, 0.0));
+    /// ```
+    fn as_clock_angles(&self) -> (f64, f64, f64);
+
+    /// Treating the time span as the time elapsed so far, returns an estimate of the time
+    /// remaining once `fraction_done` of the work is complete.
+    ///
+    /// The estimate is `elapsed * (1 - fraction_done) / fraction_done`. Returns `None` if
+    /// `fraction_done` is not in the range `(0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let elapsed = Duration::from_seconds(30);
+    /// assert_eq!(elapsed.estimate_remaining(0.75), Some(Duration::from_seconds(10)));
+    /// ```
+    fn estimate_remaining(&self, fraction_done: f64) -> Option<T>;
+
+    /// Returns the sum of the two time spans, or an error if the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::from_seconds(5).add_span(Duration::from_seconds(10));
+    /// assert_eq!(span, Ok(Duration::from_seconds(15)));
+    /// ```
+    fn add_span(&self, other: T) -> Result<T, TimeSpanError>;
+    /// Returns the difference between the two time spans, or an error if the result would be
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::{TimeSpan, TimeSpanError};
+    /// 
+    /// let span = Duration::from_seconds(5).sub_span(Duration::from_seconds(10));
+    /// assert_eq!(span, Err(TimeSpanError::Negative));
+    /// ```
+    fn sub_span(&self, other: T) -> Result<T, TimeSpanError>;
+
+    /// Returns `min` if the time span is shorter than `min`, `max` if it is longer than `max`,
+    /// and the time span itself otherwise.
+    ///
+    /// In debug builds this will panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::from_seconds(90);
+    /// assert_eq!(span.clamp_span(Duration::from_seconds(1), Duration::from_seconds(60)), Duration::from_seconds(60));
+    /// ```
+    fn clamp_span(&self, min: T, max: T) -> T;
+
+    /// Splits the time span into parts proportional to the given weights, such as for dividing
+    /// a time budget between tasks. Each part is rounded to the nearest nanosecond in a way that
+    /// keeps the sum of the parts exactly equal to the original time span.
+    ///
+    /// Returns an empty `Vec` if any weight is negative or not finite, or if the weights sum to
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let parts = Duration::from_seconds(10).allocate(&[1.0, 3.0]);
+    /// assert_eq!(parts, vec![Duration::from_milliseconds(2500), Duration::from_milliseconds(7500)]);
+    /// ```
+    fn allocate(&self, weights: &[f64]) -> Vec<T>;
+
+    /// Returns an approximate, human-friendly description of the time span, such as `"2 days"`
+    /// or `"about 3 hours"`.
+    ///
+    /// The largest whole unit out of days, hours, minutes, and seconds is used, rounded down.
+    /// The phrase is prefixed with "about" if any smaller units were dropped. Time spans under a
+    /// second are described as `"less than a second"`, except for zero which is `"0 seconds"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// assert_eq!(Duration::from_days(2).humanize(), "2 days");
+    /// assert_eq!(Duration::from_minutes(90).humanize(), "about 1 hour");
+    /// ```
+    fn humanize(&self) -> String;
+
+    /// Returns the longest representable time span, for use as the starting value when finding
+    /// the minimum of several spans.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let spans = [Duration::from_seconds(5), Duration::from_seconds(2)];
+    /// let shortest = spans.iter().fold(Duration::max_span(), |acc, &s| acc.min(s));
+    /// assert_eq!(shortest, Duration::from_seconds(2));
+    /// ```
+    fn max_span() -> T;
+
+    /// Returns the shortest representable time span, for use as the starting value when finding
+    /// the maximum of several spans. Negative time spans aren't supported, so this is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let spans = [Duration::from_seconds(5), Duration::from_seconds(2)];
+    /// let longest = spans.iter().fold(Duration::min_span(), |acc, &s| acc.max(s));
+    /// assert_eq!(longest, Duration::from_seconds(5));
+    /// ```
+    fn min_span() -> T;
+
+    /// Returns the time span as a fraction of `whole`, such as `0.5` when it is half as long.
+    ///
+    /// This follows floating point division, so if `whole` is zero the result is infinity, or
+    /// NaN if the time span is also zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let done = Duration::from_seconds(30);
+    /// assert_eq!(done.ratio(Duration::from_minutes(2)), 0.25);
+    /// ```
+    fn ratio(&self, whole: T) -> f64;
+
+    /// Returns the absolute difference between two time spans, whichever is longer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let a = Duration::from_seconds(10);
+    /// let b = Duration::from_seconds(3);
+    /// assert_eq!(a.diff(b), Duration::from_seconds(7));
+    /// assert_eq!(b.diff(a), Duration::from_seconds(7));
+    /// ```
+    fn diff(&self, other: T) -> T;
+
+    /// Formats the time span as `d.hh:mm:ss`, followed by a decimal point and exactly
+    /// `frac_digits` digits of fractional seconds if `frac_digits` is not zero.
+    ///
+    /// The time span is rounded to the nearest unit of the last digit shown, with halves rounded
+    /// up. Digits beyond nanoseconds are always zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rust_utils::time::TimeSpan;
+    /// 
+    /// let span = Duration::from_dhms(1, 2, 3, 4) + Duration::from_milliseconds(567);
+    /// assert_eq!(span.format_with_precision(0), "1.02:03:05");
+    /// assert_eq!(span.format_with_precision(2), "1.02:03:04.57");
+    /// ```
+    fn format_with_precision(&self, frac_digits: usize) -> String;
 }
 
 macro_rules! input_check {
@@ -279,6 +634,24 @@ impl TimeSpan<Duration> for Duration {
     fn partial_milliseconds(&self) -> u16{
         (self.subsec_nanos() / NANOS_PER_MILLISECOND) as u16
     }
+    fn partial_microseconds(&self) -> u32 {
+        (self.subsec_nanos() % NANOS_PER_MILLISECOND) / NANOS_PER_MICROSECOND
+    }
+    fn partial_nanoseconds(&self) -> u32 {
+        self.subsec_nanos() % NANOS_PER_MILLISECOND
+    }
+    fn components(&self) -> (u64, u8, u8, u8, u16) {
+        let secs = self.as_secs();
+        let days = secs / SECONDS_PER_DAY;
+        let secs = secs % SECONDS_PER_DAY;
+        let hours = secs / SECONDS_PER_HOUR;
+        let secs = secs % SECONDS_PER_HOUR;
+        let minutes = secs / SECONDS_PER_MINUTE;
+        let secs = secs % SECONDS_PER_MINUTE;
+        let millis = self.subsec_nanos() / NANOS_PER_MILLISECOND;
+
+        (days, hours as u8, minutes as u8, secs as u8, millis as u16)
+    }
 
     fn total_days(&self) -> f64 {
         let total_days = self.as_secs() as f64 / SECONDS_PER_DAY as f64;
@@ -305,7 +678,22 @@ impl TimeSpan<Duration> for Duration {
         let total_nanoseconds = self.subsec_nanos() as f64 / NANOS_PER_MILLISECOND_F;
         total_milliseconds + total_nanoseconds
     }
+    fn total_microseconds(&self) -> f64 {
+        let total_microseconds = self.as_secs() as f64 * MICROS_PER_SECOND as f64;
+        let total_nanoseconds = self.subsec_nanos() as f64 / NANOS_PER_MICROSECOND_F;
+        total_microseconds + total_nanoseconds
+    }
+    fn total_nanoseconds(&self) -> f64 {
+        let total_seconds_in_nano = self.as_secs() as f64 * NANOS_PER_SECOND_F;
+        let total_nanoseconds = self.subsec_nanos() as f64;
+        total_seconds_in_nano + total_nanoseconds
+    }
 
+    fn from_total_weeks(weeks: f64) -> Result<Duration, String> {
+        input_check!(weeks);
+
+        Duration::from_total_days(weeks * DAYS_PER_WEEK as f64)
+    }
     fn from_total_days(days: f64) -> Result<Duration, String> {
         input_check!(days);
 
@@ -348,6 +736,19 @@ impl TimeSpan<Duration> for Duration {
 
         Ok(Duration::new(0, milliseconds_in_nano_sec))
     }
+    fn from_total_microseconds(microseconds: f64) -> Result<Duration, String> {
+        input_check!(microseconds);
+
+        Duration::from_total_nanoseconds(microseconds * NANOS_PER_MICROSECOND_F)
+    }
+    fn from_total_nanoseconds(nanoseconds: f64) -> Result<Duration, String> {
+        input_check!(nanoseconds);
+
+        let full_seconds = (nanoseconds / NANOS_PER_SECOND_F).trunc();
+        let frac_seconds_in_nano = (nanoseconds - full_seconds * NANOS_PER_SECOND_F).round() as u32;
+
+        Ok(Duration::new(full_seconds as u64, frac_seconds_in_nano))
+    }
 
     fn from_days(days: u64) -> Duration {
         Duration::new(days * SECONDS_PER_DAY, 0)
@@ -367,13 +768,180 @@ impl TimeSpan<Duration> for Duration {
 
         Duration::new(secs, nanos)
     }
+    fn from_microseconds(microseconds: u64) -> Duration {
+        let secs = microseconds / MICROS_PER_SECOND;
+        let nanos = (microseconds % MICROS_PER_SECOND) as u32 * NANOS_PER_MICROSECOND;
+
+        Duration::new(secs, nanos)
+    }
+    fn from_nanoseconds(nanoseconds: u64) -> Duration {
+        let secs = nanoseconds / NANOS_PER_SECOND;
+        let nanos = (nanoseconds % NANOS_PER_SECOND) as u32;
+
+        Duration::new(secs, nanos)
+    }
+    fn from_hms(hours: u64, minutes: u64, seconds: u64) -> Duration {
+        Duration::from_dhms(0, hours, minutes, seconds)
+    }
+    fn from_dhms(days: u64, hours: u64, minutes: u64, seconds: u64) -> Duration {
+        let secs = days.checked_mul(SECONDS_PER_DAY)
+            .and_then(|s| hours.checked_mul(SECONDS_PER_HOUR).and_then(|h| s.checked_add(h)))
+            .and_then(|s| minutes.checked_mul(SECONDS_PER_MINUTE).and_then(|m| s.checked_add(m)))
+            .and_then(|s| s.checked_add(seconds))
+            .expect("overflow when combining time span fields");
+
+        Duration::new(secs, 0)
+    }
+
+    fn as_clock_angles(&self) -> (f64, f64, f64) {
+        let seconds = (self.as_secs() % SECONDS_PER_DAY) as f64 + self.subsec_nanos() as f64 / NANOS_PER_SECOND_F;
+
+        let hours = (seconds / SECONDS_PER_HOUR as f64) % 12.0;
+        let minutes = (seconds / SECONDS_PER_MINUTE as f64) % 60.0;
+        let seconds = seconds % 60.0;
+
+        (hours * DEGREES_PER_HOUR, minutes * DEGREES_PER_MINUTE, seconds * DEGREES_PER_MINUTE)
+    }
+
+    fn estimate_remaining(&self, fraction_done: f64) -> Option<Duration> {
+        if !(fraction_done > 0.0 && fraction_done <= 1.0) {
+            return None;
+        }
+
+        let remaining = self.total_seconds() * (1.0 - fraction_done) / fraction_done;
+        Duration::from_total_seconds(remaining).ok()
+    }
+
+    fn add_span(&self, other: Duration) -> Result<Duration, TimeSpanError> {
+        self.checked_add(other).ok_or(TimeSpanError::Overflow)
+    }
+    fn sub_span(&self, other: Duration) -> Result<Duration, TimeSpanError> {
+        self.checked_sub(other).ok_or(TimeSpanError::Negative)
+    }
+
+    fn clamp_span(&self, min: Duration, max: Duration) -> Duration {
+        debug_assert!(min <= max, "clamp_span called with min greater than max");
+
+        if *self < min {
+            min
+        } else if *self > max {
+            max
+        } else {
+            *self
+        }
+    }
+
+    fn allocate(&self, weights: &[f64]) -> Vec<Duration> {
+        if weights.iter().any(|w| w.is_sign_negative() || !w.is_finite()) {
+            return vec![];
+        }
+
+        let weight_sum: f64 = weights.iter().sum();
+        if weight_sum <= 0.0 {
+            return vec![];
+        }
+
+        // Each part is the gap between rounded cumulative boundaries, so the rounding errors
+        // cancel out and the final boundary lands exactly on the total.
+        let total = self.as_secs() as u128 * NANOS_PER_SECOND as u128 + self.subsec_nanos() as u128;
+        let mut cumulative_weight = 0.0;
+        let mut prev_boundary = 0;
+
+        weights.iter().enumerate().map(|(i, &w)| {
+            cumulative_weight += w;
+
+            let boundary = if i == weights.len() - 1 {
+                total
+            } else {
+                ((total as f64 * cumulative_weight / weight_sum).round() as u128).min(total)
+            };
+
+            let part = boundary - prev_boundary;
+            prev_boundary = boundary;
+
+            Duration::new((part / NANOS_PER_SECOND as u128) as u64, (part % NANOS_PER_SECOND as u128) as u32)
+        }).collect()
+    }
+
+    fn humanize(&self) -> String {
+        let secs = self.as_secs();
+        if secs == 0 {
+            return if self.subsec_nanos() == 0 {
+                "0 seconds".to_owned()
+            } else {
+                "less than a second".to_owned()
+            };
+        }
+
+        let (count, unit, unit_secs) = if secs >= SECONDS_PER_DAY {
+            (secs / SECONDS_PER_DAY, "day", SECONDS_PER_DAY)
+        } else if secs >= SECONDS_PER_HOUR {
+            (secs / SECONDS_PER_HOUR, "hour", SECONDS_PER_HOUR)
+        } else if secs >= SECONDS_PER_MINUTE {
+            (secs / SECONDS_PER_MINUTE, "minute", SECONDS_PER_MINUTE)
+        } else {
+            (secs, "second", 1)
+        };
+
+        let exact = secs % unit_secs == 0 && self.subsec_nanos() == 0;
+        let prefix = if exact { "" } else { "about " };
+        let plural = if count == 1 { "" } else { "s" };
+
+        format!("{}{} {}{}", prefix, count, unit, plural)
+    }
+
+    fn max_span() -> Duration {
+        Duration::new(u64::max_value(), NANOS_PER_SECOND as u32 - 1)
+    }
+    fn min_span() -> Duration {
+        Duration::new(0, 0)
+    }
+
+    fn ratio(&self, whole: Duration) -> f64 {
+        let nanos = |d: &Duration| d.as_secs() as u128 * NANOS_PER_SECOND as u128 + d.subsec_nanos() as u128;
+
+        nanos(self) as f64 / nanos(&whole) as f64
+    }
+
+    fn diff(&self, other: Duration) -> Duration {
+        if *self >= other {
+            *self - other
+        } else {
+            other - *self
+        }
+    }
+
+    fn format_with_precision(&self, frac_digits: usize) -> String {
+        let shown_digits = frac_digits.min(9) as u32;
+        let unit = 10_u128.pow(9 - shown_digits);
+
+        let nanos = self.as_secs() as u128 * NANOS_PER_SECOND as u128 + self.subsec_nanos() as u128;
+        let rounded = (nanos + unit / 2) / unit * unit;
+
+        let secs = rounded / NANOS_PER_SECOND as u128;
+        let days = secs / SECONDS_PER_DAY as u128;
+        let hours = (secs % SECONDS_PER_DAY as u128) / SECONDS_PER_HOUR as u128;
+        let minutes = (secs % SECONDS_PER_HOUR as u128) / SECONDS_PER_MINUTE as u128;
+        let seconds = secs % SECONDS_PER_MINUTE as u128;
+
+        let mut text = format!("{}.{:02}:{:02}:{:02}", days, hours, minutes, seconds);
+        if frac_digits > 0 {
+            let frac = (rounded % NANOS_PER_SECOND as u128) / unit;
+            text += &format!(".{:0width$}", frac, width = shown_digits as usize);
+            text.extend((shown_digits as usize..frac_digits).map(|_| '0'));
+        }
+
+        text
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
     use std::f64;
+    use std::u64;
     use super::TimeSpan;
+    use time::TimeSpanError;
 
     #[test]
     fn input_negative() {
@@ -397,6 +965,13 @@ mod tests {
     }
 
 
+    #[test]
+    fn from_total_weeks() {
+        assert_eq!(Duration::from_total_weeks(2.0), Ok(Duration::from_days(14)));
+        assert_eq!(Duration::from_total_weeks(0.5), Ok(Duration::from_hours(84)));
+        assert!(Duration::from_total_weeks(-1.0).is_err());
+    }
+
     #[test]
     fn from_total_days_two_weeks() {
         let span = Duration::from_total_days(14.0).unwrap();
@@ -507,6 +1082,35 @@ mod tests {
     }
 
 
+    #[test]
+    fn from_total_microseconds_one_and_half_microseconds() {
+        let span = Duration::from_total_microseconds(1.5).unwrap();
+
+        assert_eq!(span, Duration::new(0, 1_500));
+    }
+
+    #[test]
+    fn from_total_microseconds_over_one_second() {
+        let span = Duration::from_total_microseconds(2_500_000.25).unwrap();
+
+        assert_eq!(span, Duration::new(2, 500_000_250));
+    }
+
+    #[test]
+    fn from_total_nanoseconds_over_one_second() {
+        let span = Duration::from_total_nanoseconds(1_000_000_001.0).unwrap();
+
+        assert_eq!(span, Duration::new(1, 1));
+    }
+
+    #[test]
+    fn from_total_nanoseconds_rounds() {
+        let span = Duration::from_total_nanoseconds(2.6).unwrap();
+
+        assert_eq!(span, Duration::new(0, 3));
+    }
+
+
     #[test]
     fn from_days_two_weeks() {
         let span = Duration::from_days(14);
@@ -544,6 +1148,45 @@ mod tests {
 
 
 
+    #[test]
+    fn from_microseconds_two_microseconds() {
+        let span = Duration::from_microseconds(2);
+
+        assert_eq!(span, Duration::new(0, 2_000));
+    }
+
+    #[test]
+    fn from_microseconds_over_one_second() {
+        let span = Duration::from_microseconds(1_000_002);
+
+        assert_eq!(span, Duration::new(1, 2_000));
+    }
+
+    #[test]
+    fn from_nanoseconds_over_one_second() {
+        let span = Duration::from_nanoseconds(3_000_000_002);
+
+        assert_eq!(span, Duration::new(3, 2));
+    }
+
+    #[test]
+    fn from_microseconds_second_boundary() {
+        assert_eq!(Duration::from_microseconds(999_999), Duration::new(0, 999_999_000));
+        assert_eq!(Duration::from_microseconds(1_000_000), Duration::new(1, 0));
+        assert_eq!(Duration::from_microseconds(1_000_001), Duration::new(1, 1_000));
+        assert_eq!(Duration::from_microseconds(u64::max_value()), Duration::new(18_446_744_073_709, 551_615_000));
+    }
+
+    #[test]
+    fn from_nanoseconds_second_boundary() {
+        assert_eq!(Duration::from_nanoseconds(999_999_999), Duration::new(0, 999_999_999));
+        assert_eq!(Duration::from_nanoseconds(1_000_000_000), Duration::new(1, 0));
+        assert_eq!(Duration::from_nanoseconds(1_000_000_001), Duration::new(1, 1));
+        assert_eq!(Duration::from_nanoseconds(u64::max_value()), Duration::new(18_446_744_073, 709_551_615));
+    }
+
+
+
     #[test]
     fn partial_days() {
         let span = Duration::from_total_days(1.51354973541463).unwrap();
@@ -579,6 +1222,29 @@ mod tests {
         assert_eq!(span.partial_milliseconds(), 697);
     }
 
+    #[test]
+    fn partial_microseconds() {
+        let span = Duration::new(5, 123_456_789);
+
+        assert_eq!(span.partial_microseconds(), 456);
+    }
+
+    #[test]
+    fn partial_nanoseconds() {
+        let span = Duration::new(5, 123_456_789);
+
+        assert_eq!(span.partial_nanoseconds(), 456_789);
+    }
+
+    #[test]
+    fn components() {
+        let span = Duration::from_total_days(1.51354973541463).unwrap();
+        let expected = (span.partial_days(), span.partial_hours(), span.partial_minutes(), span.partial_seconds(), span.partial_milliseconds());
+
+        assert_eq!(span.components(), expected);
+        assert_eq!(span.components(), (1, 12, 19, 30, 697));
+    }
+
     #[test]
     fn total_days() {
         let span = Duration::from_total_days(1.5135497354).unwrap();
@@ -628,4 +1294,234 @@ mod tests {
 
         assert_eq!(span, 130770697.1);
     }
-}
+
+    #[test]
+    fn clock_angles_three_oclock() {
+        let span = Duration::from_hours(3);
+
+        assert_eq!(span.as_clock_angles(), (90.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn clock_angles_half_past_one() {
+        let span = Duration::from_minutes(90);
+        let (hour, minute, second) = span.as_clock_angles();
+
+        assert_eq!(hour, 45.0);
+        assert_eq!(minute, 180.0);
+        assert_eq!(second, 0.0);
+    }
+
+    #[test]
+    fn clock_angles_ignores_days() {
+        let span = Duration::from_days(2) + Duration::from_hours(15);
+
+        assert_eq!(span.as_clock_angles(), (90.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn estimate_remaining_half_done() {
+        let span = Duration::from_seconds(10);
+
+        assert_eq!(span.estimate_remaining(0.5), Some(Duration::from_seconds(10)));
+    }
+
+    #[test]
+    fn estimate_remaining_complete() {
+        let span = Duration::from_seconds(10);
+
+        assert_eq!(span.estimate_remaining(1.0), Some(Duration::new(0, 0)));
+    }
+
+    #[test]
+    fn estimate_remaining_invalid_fraction() {
+        let span = Duration::from_seconds(10);
+
+        assert_eq!(span.estimate_remaining(0.0), None);
+        assert_eq!(span.estimate_remaining(-0.5), None);
+        assert_eq!(span.estimate_remaining(1.5), None);
+        assert_eq!(span.estimate_remaining(f64::NAN), None);
+    }
+
+    #[test]
+    fn total_microseconds() {
+        let span = Duration::new(1, 500_250);
+
+        assert_eq!(span.total_microseconds(), 1_000_500.25);
+    }
+
+    #[test]
+    fn total_nanoseconds() {
+        let span = Duration::new(1, 500_250);
+
+        assert_eq!(span.total_nanoseconds(), 1_000_500_250.0);
+    }
+
+    #[test]
+    fn add_span() {
+        let span = Duration::from_seconds(5).add_span(Duration::new(10, 500));
+
+        assert_eq!(span, Ok(Duration::new(15, 500)));
+    }
+
+    #[test]
+    fn add_span_overflow() {
+        let span = Duration::new(u64::MAX, 0).add_span(Duration::from_seconds(1));
+
+        assert_eq!(span, Err(TimeSpanError::Overflow));
+    }
+
+    #[test]
+    fn sub_span() {
+        let span = Duration::from_seconds(10).sub_span(Duration::from_seconds(5));
+
+        assert_eq!(span, Ok(Duration::from_seconds(5)));
+    }
+
+    #[test]
+    fn sub_span_negative() {
+        let span = Duration::from_seconds(5).sub_span(Duration::from_seconds(10));
+
+        assert_eq!(span, Err(TimeSpanError::Negative));
+    }
+
+    #[test]
+    fn clamp_span() {
+        let min = Duration::from_seconds(2);
+        let max = Duration::from_seconds(5);
+
+        assert_eq!(Duration::from_seconds(1).clamp_span(min, max), min);
+        assert_eq!(Duration::from_seconds(3).clamp_span(min, max), Duration::from_seconds(3));
+        assert_eq!(Duration::from_seconds(8).clamp_span(min, max), max);
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp_span called with min greater than max")]
+    #[cfg(debug_assertions)]
+    fn clamp_span_inverted_bounds() {
+        Duration::from_seconds(3).clamp_span(Duration::from_seconds(5), Duration::from_seconds(2));
+    }
+
+    #[test]
+    fn allocate() {
+        let parts = Duration::from_seconds(10).allocate(&[1.0, 3.0]);
+
+        assert_eq!(parts, vec![Duration::new(2, 500_000_000), Duration::new(7, 500_000_000)]);
+    }
+
+    #[test]
+    fn allocate_sums_exactly() {
+        let span = Duration::new(1, 1);
+        let parts = span.allocate(&[1.0, 1.0, 1.0]);
+
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts.iter().fold(Duration::new(0, 0), |acc, &p| acc + p), span);
+    }
+
+    #[test]
+    fn allocate_invalid_weights() {
+        let span = Duration::from_seconds(10);
+
+        assert_eq!(span.allocate(&[]), vec![]);
+        assert_eq!(span.allocate(&[0.0, 0.0]), vec![]);
+        assert_eq!(span.allocate(&[1.0, -1.0]), vec![]);
+        assert_eq!(span.allocate(&[1.0, f64::NAN]), vec![]);
+    }
+
+    #[test]
+    fn humanize() {
+        assert_eq!(Duration::from_minutes(90).humanize(), "about 1 hour");
+        assert_eq!(Duration::from_days(2).humanize(), "2 days");
+        assert_eq!(Duration::from_hours(3).humanize(), "3 hours");
+        assert_eq!(Duration::from_seconds(61).humanize(), "about 1 minute");
+        assert_eq!(Duration::from_seconds(45).humanize(), "45 seconds");
+        assert_eq!(Duration::new(1, 500_000_000).humanize(), "about 1 second");
+    }
+
+    #[test]
+    fn humanize_sub_second() {
+        assert_eq!(Duration::new(0, 0).humanize(), "0 seconds");
+        assert_eq!(Duration::from_milliseconds(500).humanize(), "less than a second");
+    }
+
+    #[test]
+    fn from_hms_carries() {
+        assert_eq!(Duration::from_hms(0, 90, 0), Duration::from_minutes(90));
+        assert_eq!(Duration::from_hms(0, 90, 0).total_hours(), 1.5);
+        assert_eq!(Duration::from_hms(1, 0, 3600), Duration::from_hours(2));
+        assert_eq!(Duration::from_hms(2, 3, 4).components(), (0, 2, 3, 4, 0));
+    }
+
+    #[test]
+    fn from_dhms_carries() {
+        assert_eq!(Duration::from_dhms(1, 24, 0, 0), Duration::from_days(2));
+        assert_eq!(Duration::from_dhms(1, 2, 3, 4).components(), (1, 2, 3, 4, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when combining time span fields")]
+    fn from_hms_overflow() {
+        Duration::from_hms(u64::MAX, 0, 0);
+    }
+
+    #[test]
+    fn span_bounds() {
+        let spans = [Duration::new(0, 0), Duration::new(0, 1), Duration::from_days(10_000), Duration::new(u64::MAX, 999_999_999)];
+
+        for &span in &spans {
+            assert!(span <= Duration::max_span());
+            assert!(span >= Duration::min_span());
+        }
+
+        assert_eq!(Duration::max_span(), Duration::new(u64::MAX, 999_999_999));
+        assert_eq!(Duration::min_span(), Duration::new(0, 0));
+    }
+
+    #[test]
+    fn ratio() {
+        let whole = Duration::from_seconds(10);
+
+        assert_eq!(Duration::from_seconds(5).ratio(whole), 0.5);
+        assert_eq!(whole.ratio(whole), 1.0);
+        assert_eq!(Duration::new(0, 1).ratio(Duration::new(0, 4)), 0.25);
+    }
+
+    #[test]
+    fn ratio_zero_whole() {
+        let zero = Duration::new(0, 0);
+
+        assert_eq!(Duration::from_seconds(5).ratio(zero), f64::INFINITY);
+        assert!(zero.ratio(zero).is_nan());
+    }
+
+    #[test]
+    fn diff() {
+        let ten = Duration::from_seconds(10);
+        let three = Duration::from_seconds(3);
+
+        assert_eq!(ten.diff(three), Duration::from_seconds(7));
+        assert_eq!(three.diff(ten), Duration::from_seconds(7));
+        assert_eq!(ten.diff(ten), Duration::new(0, 0));
+    }
+
+    #[test]
+    fn format_with_precision() {
+        let span = Duration::from_dhms(0, 1, 2, 3) + Duration::new(0, 123_456_789);
+
+        assert_eq!(span.format_with_precision(0), "0.01:02:03");
+        assert_eq!(span.format_with_precision(3), "0.01:02:03.123");
+        assert_eq!(span.format_with_precision(9), "0.01:02:03.123456789");
+        assert_eq!(span.format_with_precision(11), "0.01:02:03.12345678900");
+    }
+
+    #[test]
+    fn format_with_precision_rounds() {
+        let span = Duration::from_dhms(2, 23, 59, 59) + Duration::new(0, 999_500_000);
+
+        assert_eq!(span.format_with_precision(0), "3.00:00:00");
+        assert_eq!(span.format_with_precision(3), "3.00:00:00.000");
+        assert_eq!(span.format_with_precision(4), "2.23:59:59.9995");
+        assert_eq!(Duration::new(0, 1_500_000).format_with_precision(2), "0.00:00:00.00");
+        assert_eq!(Duration::new(0, 5_000_000).format_with_precision(2), "0.00:00:00.01");
+    }
+}
\ No newline at end of file